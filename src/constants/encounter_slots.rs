@@ -0,0 +1,19 @@
+/*
+    出現スロットごとの出現率 (%)。先頭のスロットから順に slot_value (0 ~ 99) を割り当てていく。
+    例: くさむらで slot_value が 45 なら 20 + 20 <= 45 < 20 + 20 + 10 なのでスロット 2。
+*/
+pub static GRASS: [u8; 12] = [20, 20, 10, 10, 10, 10, 5, 5, 4, 4, 1, 1];
+
+pub static SURFING: [u8; 5] = [60, 30, 5, 4, 1];
+
+// DPPt のボロいつりざお
+pub static OLD_ROD_DPPT: [u8; 5] = [60, 30, 5, 4, 1];
+
+// DPPt のいいつりざお/すごいつりざお、HGSS の全てのつりざお
+pub static ROD: [u8; 5] = [40, 40, 15, 4, 1];
+
+// HGSS のいわくだき
+pub static ROCK_SMASH: [u8; 2] = [80, 20];
+
+// HGSS のずつき
+pub static HEADBUTT: [u8; 6] = [50, 15, 15, 10, 5, 5];
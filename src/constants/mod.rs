@@ -1,2 +1,4 @@
+pub mod encounter_slots;
 pub mod roaming_routes;
+pub mod species;
 pub mod time_sum_map;
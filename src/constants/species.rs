@@ -0,0 +1,577 @@
+use crate::types::{gender::*, species::*};
+
+use EggGroup::*;
+use GrowthRate::*;
+
+const fn species(
+    dex: u16,
+    name: &'static str,
+    base_stats: [u8; 6],
+    gender_ratio: u8,
+    abilities: [&'static str; 2],
+    egg_groups: [EggGroup; 2],
+    growth_rate: GrowthRate,
+) -> SpeciesInfo {
+    let [hp, attack, defense, speed, sp_attack, sp_defense] = base_stats;
+
+    return SpeciesInfo {
+        dex,
+        name,
+        base_stats: BaseStats {
+            hp,
+            attack,
+            defense,
+            speed,
+            sp_attack,
+            sp_defense,
+        },
+        gender_ratio,
+        abilities,
+        egg_groups,
+        growth_rate,
+    };
+}
+
+pub fn by_dex(dex: u16) -> Option<&'static SpeciesInfo> {
+    return SPECIES.get((dex as usize).checked_sub(1)?);
+}
+
+// 英語名で探す (大文字/小文字は区別しない)
+pub fn by_name(name: &str) -> Option<&'static SpeciesInfo> {
+    return SPECIES
+        .iter()
+        .find(|species| species.name.eq_ignore_ascii_case(name.trim()));
+}
+
+// 種族の性別の閾値 (分からない種族は None)
+pub fn gender_ratio(dex: u16) -> Option<u8> {
+    return by_dex(dex).map(|species| species.gender_ratio);
+}
+
+/*
+    全国図鑑番号順 (1 ~ 493)
+    種族値は [hp, attack, defense, speed, sp_attack, sp_defense] の順。
+*/
+#[rustfmt::skip]
+pub static SPECIES: [SpeciesInfo; 493] = [
+    species(1, "Bulbasaur", [45, 49, 49, 45, 65, 65], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(2, "Ivysaur", [60, 62, 63, 60, 80, 80], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(3, "Venusaur", [80, 82, 83, 80, 100, 100], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(4, "Charmander", [39, 52, 43, 65, 60, 50], 0x1f, ["Blaze", "Blaze"], [Monster, Dragon], MediumSlow),
+    species(5, "Charmeleon", [58, 64, 58, 80, 80, 65], 0x1f, ["Blaze", "Blaze"], [Monster, Dragon], MediumSlow),
+    species(6, "Charizard", [78, 84, 78, 100, 109, 85], 0x1f, ["Blaze", "Blaze"], [Monster, Dragon], MediumSlow),
+    species(7, "Squirtle", [44, 48, 65, 43, 50, 64], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(8, "Wartortle", [59, 63, 80, 58, 65, 80], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(9, "Blastoise", [79, 83, 100, 78, 85, 105], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(10, "Caterpie", [45, 30, 35, 45, 20, 20], 0x7f, ["Shield Dust", "Shield Dust"], [Bug, Bug], MediumFast),
+    species(11, "Metapod", [50, 20, 55, 30, 25, 25], 0x7f, ["Shed Skin", "Shed Skin"], [Bug, Bug], MediumFast),
+    species(12, "Butterfree", [60, 45, 50, 70, 80, 80], 0x7f, ["Compound Eyes", "Compound Eyes"], [Bug, Bug], MediumFast),
+    species(13, "Weedle", [40, 35, 30, 50, 20, 20], 0x7f, ["Shield Dust", "Shield Dust"], [Bug, Bug], MediumFast),
+    species(14, "Kakuna", [45, 25, 50, 35, 25, 25], 0x7f, ["Shed Skin", "Shed Skin"], [Bug, Bug], MediumFast),
+    species(15, "Beedrill", [65, 80, 40, 75, 45, 80], 0x7f, ["Swarm", "Swarm"], [Bug, Bug], MediumFast),
+    species(16, "Pidgey", [40, 45, 40, 56, 35, 35], 0x7f, ["Keen Eye", "Tangled Feet"], [Flying, Flying], MediumSlow),
+    species(17, "Pidgeotto", [63, 60, 55, 71, 50, 50], 0x7f, ["Keen Eye", "Tangled Feet"], [Flying, Flying], MediumSlow),
+    species(18, "Pidgeot", [83, 80, 75, 91, 70, 70], 0x7f, ["Keen Eye", "Tangled Feet"], [Flying, Flying], MediumSlow),
+    species(19, "Rattata", [30, 56, 35, 72, 25, 35], 0x7f, ["Run Away", "Guts"], [Field, Field], MediumFast),
+    species(20, "Raticate", [55, 81, 60, 97, 50, 70], 0x7f, ["Run Away", "Guts"], [Field, Field], MediumFast),
+    species(21, "Spearow", [40, 60, 30, 70, 31, 31], 0x7f, ["Keen Eye", "Keen Eye"], [Flying, Flying], MediumFast),
+    species(22, "Fearow", [65, 90, 65, 100, 61, 61], 0x7f, ["Keen Eye", "Keen Eye"], [Flying, Flying], MediumFast),
+    species(23, "Ekans", [35, 60, 44, 55, 40, 54], 0x7f, ["Intimidate", "Shed Skin"], [Field, Dragon], MediumFast),
+    species(24, "Arbok", [60, 85, 69, 80, 65, 79], 0x7f, ["Intimidate", "Shed Skin"], [Field, Dragon], MediumFast),
+    species(25, "Pikachu", [35, 55, 30, 90, 50, 40], 0x7f, ["Static", "Static"], [Field, Fairy], MediumFast),
+    species(26, "Raichu", [60, 90, 55, 100, 90, 80], 0x7f, ["Static", "Static"], [Field, Fairy], MediumFast),
+    species(27, "Sandshrew", [50, 75, 85, 40, 20, 30], 0x7f, ["Sand Veil", "Sand Veil"], [Field, Field], MediumFast),
+    species(28, "Sandslash", [75, 100, 110, 65, 45, 55], 0x7f, ["Sand Veil", "Sand Veil"], [Field, Field], MediumFast),
+    species(29, "Nidoran♀", [55, 47, 52, 41, 40, 40], FEMALE_ONLY, ["Poison Point", "Rivalry"], [Monster, Field], MediumSlow),
+    species(30, "Nidorina", [70, 62, 67, 56, 55, 55], FEMALE_ONLY, ["Poison Point", "Rivalry"], [Undiscovered, Undiscovered], MediumSlow),
+    species(31, "Nidoqueen", [90, 82, 87, 76, 75, 85], FEMALE_ONLY, ["Poison Point", "Rivalry"], [Undiscovered, Undiscovered], MediumSlow),
+    species(32, "Nidoran♂", [46, 57, 40, 50, 40, 40], MALE_ONLY, ["Poison Point", "Rivalry"], [Monster, Field], MediumSlow),
+    species(33, "Nidorino", [61, 72, 57, 65, 55, 55], MALE_ONLY, ["Poison Point", "Rivalry"], [Monster, Field], MediumSlow),
+    species(34, "Nidoking", [81, 92, 77, 85, 85, 75], MALE_ONLY, ["Poison Point", "Rivalry"], [Monster, Field], MediumSlow),
+    species(35, "Clefairy", [70, 45, 48, 35, 60, 65], 0xbf, ["Cute Charm", "Magic Guard"], [Fairy, Fairy], Fast),
+    species(36, "Clefable", [95, 70, 73, 60, 85, 90], 0xbf, ["Cute Charm", "Magic Guard"], [Fairy, Fairy], Fast),
+    species(37, "Vulpix", [38, 41, 40, 65, 50, 65], 0xbf, ["Flash Fire", "Flash Fire"], [Field, Field], MediumFast),
+    species(38, "Ninetales", [73, 76, 75, 100, 81, 100], 0xbf, ["Flash Fire", "Flash Fire"], [Field, Field], MediumFast),
+    species(39, "Jigglypuff", [115, 45, 20, 20, 45, 25], 0xbf, ["Cute Charm", "Cute Charm"], [Fairy, Fairy], Fast),
+    species(40, "Wigglytuff", [140, 70, 45, 45, 75, 50], 0xbf, ["Cute Charm", "Cute Charm"], [Fairy, Fairy], Fast),
+    species(41, "Zubat", [40, 45, 35, 55, 30, 40], 0x7f, ["Inner Focus", "Inner Focus"], [Flying, Flying], MediumFast),
+    species(42, "Golbat", [75, 80, 70, 90, 65, 75], 0x7f, ["Inner Focus", "Inner Focus"], [Flying, Flying], MediumFast),
+    species(43, "Oddish", [45, 50, 55, 30, 75, 65], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(44, "Gloom", [60, 65, 70, 40, 85, 75], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(45, "Vileplume", [75, 80, 85, 50, 100, 90], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(46, "Paras", [35, 70, 55, 25, 45, 55], 0x7f, ["Effect Spore", "Dry Skin"], [Bug, Grass], MediumFast),
+    species(47, "Parasect", [60, 95, 80, 30, 60, 80], 0x7f, ["Effect Spore", "Dry Skin"], [Bug, Grass], MediumFast),
+    species(48, "Venonat", [60, 55, 50, 45, 40, 55], 0x7f, ["Compound Eyes", "Tinted Lens"], [Bug, Bug], MediumFast),
+    species(49, "Venomoth", [70, 65, 60, 90, 90, 75], 0x7f, ["Shield Dust", "Tinted Lens"], [Bug, Bug], MediumFast),
+    species(50, "Diglett", [10, 55, 25, 95, 35, 45], 0x7f, ["Sand Veil", "Arena Trap"], [Field, Field], MediumFast),
+    species(51, "Dugtrio", [35, 80, 50, 120, 50, 70], 0x7f, ["Sand Veil", "Arena Trap"], [Field, Field], MediumFast),
+    species(52, "Meowth", [40, 45, 35, 90, 40, 40], 0x7f, ["Pickup", "Technician"], [Field, Field], MediumFast),
+    species(53, "Persian", [65, 70, 60, 115, 65, 65], 0x7f, ["Limber", "Technician"], [Field, Field], MediumFast),
+    species(54, "Psyduck", [50, 52, 48, 55, 65, 50], 0x7f, ["Damp", "Cloud Nine"], [Water1, Field], MediumFast),
+    species(55, "Golduck", [80, 82, 78, 85, 95, 80], 0x7f, ["Damp", "Cloud Nine"], [Water1, Field], MediumFast),
+    species(56, "Mankey", [40, 80, 35, 70, 35, 45], 0x7f, ["Vital Spirit", "Anger Point"], [Field, Field], MediumFast),
+    species(57, "Primeape", [65, 105, 60, 95, 60, 70], 0x7f, ["Vital Spirit", "Anger Point"], [Field, Field], MediumFast),
+    species(58, "Growlithe", [55, 70, 45, 60, 70, 50], 0x3f, ["Intimidate", "Flash Fire"], [Field, Field], Slow),
+    species(59, "Arcanine", [90, 110, 80, 95, 100, 80], 0x3f, ["Intimidate", "Flash Fire"], [Field, Field], Slow),
+    species(60, "Poliwag", [40, 50, 40, 90, 40, 40], 0x7f, ["Water Absorb", "Damp"], [Water1, Water1], MediumSlow),
+    species(61, "Poliwhirl", [65, 65, 65, 90, 50, 50], 0x7f, ["Water Absorb", "Damp"], [Water1, Water1], MediumSlow),
+    species(62, "Poliwrath", [90, 85, 95, 70, 70, 90], 0x7f, ["Water Absorb", "Damp"], [Water1, Water1], MediumSlow),
+    species(63, "Abra", [25, 20, 15, 90, 105, 55], 0x3f, ["Synchronize", "Inner Focus"], [HumanLike, HumanLike], MediumSlow),
+    species(64, "Kadabra", [40, 35, 30, 105, 120, 70], 0x3f, ["Synchronize", "Inner Focus"], [HumanLike, HumanLike], MediumSlow),
+    species(65, "Alakazam", [55, 50, 45, 120, 135, 85], 0x3f, ["Synchronize", "Inner Focus"], [HumanLike, HumanLike], MediumSlow),
+    species(66, "Machop", [70, 80, 50, 35, 35, 35], 0x3f, ["Guts", "No Guard"], [HumanLike, HumanLike], MediumSlow),
+    species(67, "Machoke", [80, 100, 70, 45, 50, 60], 0x3f, ["Guts", "No Guard"], [HumanLike, HumanLike], MediumSlow),
+    species(68, "Machamp", [90, 130, 80, 55, 65, 85], 0x3f, ["Guts", "No Guard"], [HumanLike, HumanLike], MediumSlow),
+    species(69, "Bellsprout", [50, 75, 35, 40, 70, 30], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(70, "Weepinbell", [65, 90, 50, 55, 85, 45], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(71, "Victreebel", [80, 105, 65, 70, 100, 60], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(72, "Tentacool", [40, 40, 35, 70, 50, 100], 0x7f, ["Clear Body", "Liquid Ooze"], [Water3, Water3], Slow),
+    species(73, "Tentacruel", [80, 70, 65, 100, 80, 120], 0x7f, ["Clear Body", "Liquid Ooze"], [Water3, Water3], Slow),
+    species(74, "Geodude", [40, 80, 100, 20, 30, 30], 0x7f, ["Rock Head", "Sturdy"], [Mineral, Mineral], MediumSlow),
+    species(75, "Graveler", [55, 95, 115, 35, 45, 45], 0x7f, ["Rock Head", "Sturdy"], [Mineral, Mineral], MediumSlow),
+    species(76, "Golem", [80, 110, 130, 45, 55, 65], 0x7f, ["Rock Head", "Sturdy"], [Mineral, Mineral], MediumSlow),
+    species(77, "Ponyta", [50, 85, 55, 90, 65, 65], 0x7f, ["Run Away", "Flash Fire"], [Field, Field], MediumFast),
+    species(78, "Rapidash", [65, 100, 70, 105, 80, 80], 0x7f, ["Run Away", "Flash Fire"], [Field, Field], MediumFast),
+    species(79, "Slowpoke", [90, 65, 65, 15, 40, 40], 0x7f, ["Oblivious", "Own Tempo"], [Monster, Water1], MediumFast),
+    species(80, "Slowbro", [95, 75, 110, 30, 100, 80], 0x7f, ["Oblivious", "Own Tempo"], [Monster, Water1], MediumFast),
+    species(81, "Magnemite", [25, 35, 70, 45, 95, 55], GENDERLESS, ["Magnet Pull", "Sturdy"], [Mineral, Mineral], MediumFast),
+    species(82, "Magneton", [50, 60, 95, 70, 120, 70], GENDERLESS, ["Magnet Pull", "Sturdy"], [Mineral, Mineral], MediumFast),
+    species(83, "Farfetch'd", [52, 65, 55, 60, 58, 62], 0x7f, ["Keen Eye", "Inner Focus"], [Flying, Field], MediumFast),
+    species(84, "Doduo", [35, 85, 45, 75, 35, 35], 0x7f, ["Run Away", "Early Bird"], [Flying, Flying], MediumFast),
+    species(85, "Dodrio", [60, 110, 70, 100, 60, 60], 0x7f, ["Run Away", "Early Bird"], [Flying, Flying], MediumFast),
+    species(86, "Seel", [65, 45, 55, 45, 45, 70], 0x7f, ["Thick Fat", "Hydration"], [Water1, Field], MediumFast),
+    species(87, "Dewgong", [90, 70, 80, 70, 70, 95], 0x7f, ["Thick Fat", "Hydration"], [Water1, Field], MediumFast),
+    species(88, "Grimer", [80, 80, 50, 25, 40, 50], 0x7f, ["Stench", "Sticky Hold"], [Amorphous, Amorphous], MediumFast),
+    species(89, "Muk", [105, 105, 75, 50, 65, 100], 0x7f, ["Stench", "Sticky Hold"], [Amorphous, Amorphous], MediumFast),
+    species(90, "Shellder", [30, 65, 100, 40, 45, 25], 0x7f, ["Shell Armor", "Skill Link"], [Water3, Water3], Slow),
+    species(91, "Cloyster", [50, 95, 180, 70, 85, 45], 0x7f, ["Shell Armor", "Skill Link"], [Water3, Water3], Slow),
+    species(92, "Gastly", [30, 35, 30, 80, 100, 35], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], MediumSlow),
+    species(93, "Haunter", [45, 50, 45, 95, 115, 55], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], MediumSlow),
+    species(94, "Gengar", [60, 65, 60, 110, 130, 75], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], MediumSlow),
+    species(95, "Onix", [35, 45, 160, 70, 30, 45], 0x7f, ["Rock Head", "Sturdy"], [Mineral, Mineral], MediumFast),
+    species(96, "Drowzee", [60, 48, 45, 42, 43, 90], 0x7f, ["Insomnia", "Forewarn"], [HumanLike, HumanLike], MediumFast),
+    species(97, "Hypno", [85, 73, 70, 67, 73, 115], 0x7f, ["Insomnia", "Forewarn"], [HumanLike, HumanLike], MediumFast),
+    species(98, "Krabby", [30, 105, 90, 50, 25, 25], 0x7f, ["Hyper Cutter", "Shell Armor"], [Water3, Water3], MediumFast),
+    species(99, "Kingler", [55, 130, 115, 75, 50, 50], 0x7f, ["Hyper Cutter", "Shell Armor"], [Water3, Water3], MediumFast),
+    species(100, "Voltorb", [40, 30, 50, 100, 55, 55], GENDERLESS, ["Soundproof", "Static"], [Mineral, Mineral], MediumFast),
+    species(101, "Electrode", [60, 50, 70, 140, 80, 80], GENDERLESS, ["Soundproof", "Static"], [Mineral, Mineral], MediumFast),
+    species(102, "Exeggcute", [60, 40, 80, 40, 60, 45], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], Slow),
+    species(103, "Exeggutor", [95, 95, 85, 55, 125, 65], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], Slow),
+    species(104, "Cubone", [50, 50, 95, 35, 40, 50], 0x7f, ["Rock Head", "Lightning Rod"], [Monster, Monster], MediumFast),
+    species(105, "Marowak", [60, 80, 110, 45, 50, 80], 0x7f, ["Rock Head", "Lightning Rod"], [Monster, Monster], MediumFast),
+    species(106, "Hitmonlee", [50, 120, 53, 87, 35, 110], MALE_ONLY, ["Limber", "Reckless"], [HumanLike, HumanLike], MediumFast),
+    species(107, "Hitmonchan", [50, 105, 79, 76, 35, 110], MALE_ONLY, ["Keen Eye", "Iron Fist"], [HumanLike, HumanLike], MediumFast),
+    species(108, "Lickitung", [90, 55, 75, 30, 60, 75], 0x7f, ["Own Tempo", "Oblivious"], [Monster, Monster], MediumFast),
+    species(109, "Koffing", [40, 65, 95, 35, 60, 45], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], MediumFast),
+    species(110, "Weezing", [65, 90, 120, 60, 85, 70], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], MediumFast),
+    species(111, "Rhyhorn", [80, 85, 95, 25, 30, 30], 0x7f, ["Lightning Rod", "Rock Head"], [Monster, Field], Slow),
+    species(112, "Rhydon", [105, 130, 120, 40, 45, 45], 0x7f, ["Lightning Rod", "Rock Head"], [Monster, Field], Slow),
+    species(113, "Chansey", [250, 5, 5, 50, 35, 105], FEMALE_ONLY, ["Natural Cure", "Serene Grace"], [Fairy, Fairy], Fast),
+    species(114, "Tangela", [65, 55, 115, 60, 100, 40], 0x7f, ["Chlorophyll", "Leaf Guard"], [Grass, Grass], MediumFast),
+    species(115, "Kangaskhan", [105, 95, 80, 90, 40, 80], FEMALE_ONLY, ["Early Bird", "Scrappy"], [Monster, Monster], MediumFast),
+    species(116, "Horsea", [30, 40, 70, 60, 70, 25], 0x7f, ["Swift Swim", "Sniper"], [Water1, Dragon], MediumFast),
+    species(117, "Seadra", [55, 65, 95, 85, 95, 45], 0x7f, ["Poison Point", "Sniper"], [Water1, Dragon], MediumFast),
+    species(118, "Goldeen", [45, 67, 60, 63, 35, 50], 0x7f, ["Swift Swim", "Water Veil"], [Water2, Water2], MediumFast),
+    species(119, "Seaking", [80, 92, 65, 68, 65, 80], 0x7f, ["Swift Swim", "Water Veil"], [Water2, Water2], MediumFast),
+    species(120, "Staryu", [30, 45, 55, 85, 70, 55], GENDERLESS, ["Illuminate", "Natural Cure"], [Water3, Water3], Slow),
+    species(121, "Starmie", [60, 75, 85, 115, 100, 85], GENDERLESS, ["Illuminate", "Natural Cure"], [Water3, Water3], Slow),
+    species(122, "Mr. Mime", [40, 45, 65, 90, 100, 120], 0x7f, ["Soundproof", "Filter"], [HumanLike, HumanLike], MediumFast),
+    species(123, "Scyther", [70, 110, 80, 105, 55, 80], 0x7f, ["Swarm", "Technician"], [Bug, Bug], MediumFast),
+    species(124, "Jynx", [65, 50, 35, 95, 115, 95], FEMALE_ONLY, ["Oblivious", "Forewarn"], [HumanLike, HumanLike], MediumFast),
+    species(125, "Electabuzz", [65, 83, 57, 105, 95, 85], 0x3f, ["Static", "Static"], [HumanLike, HumanLike], MediumFast),
+    species(126, "Magmar", [65, 95, 57, 93, 100, 85], 0x3f, ["Flame Body", "Flame Body"], [HumanLike, HumanLike], MediumFast),
+    species(127, "Pinsir", [65, 125, 100, 85, 55, 70], 0x7f, ["Hyper Cutter", "Mold Breaker"], [Bug, Bug], Slow),
+    species(128, "Tauros", [75, 100, 95, 110, 40, 70], MALE_ONLY, ["Intimidate", "Anger Point"], [Field, Field], Slow),
+    species(129, "Magikarp", [20, 10, 55, 80, 15, 20], 0x7f, ["Swift Swim", "Swift Swim"], [Water2, Dragon], Slow),
+    species(130, "Gyarados", [95, 125, 79, 81, 60, 100], 0x7f, ["Intimidate", "Intimidate"], [Water2, Dragon], Slow),
+    species(131, "Lapras", [130, 85, 80, 60, 85, 95], 0x7f, ["Water Absorb", "Shell Armor"], [Monster, Water1], Slow),
+    species(132, "Ditto", [48, 48, 48, 48, 48, 48], GENDERLESS, ["Limber", "Limber"], [Ditto, Ditto], MediumFast),
+    species(133, "Eevee", [55, 55, 50, 55, 45, 65], 0x1f, ["Run Away", "Adaptability"], [Field, Field], MediumFast),
+    species(134, "Vaporeon", [130, 65, 60, 65, 110, 95], 0x1f, ["Water Absorb", "Water Absorb"], [Field, Field], MediumFast),
+    species(135, "Jolteon", [65, 65, 60, 130, 110, 95], 0x1f, ["Volt Absorb", "Volt Absorb"], [Field, Field], MediumFast),
+    species(136, "Flareon", [65, 130, 60, 65, 95, 110], 0x1f, ["Flash Fire", "Flash Fire"], [Field, Field], MediumFast),
+    species(137, "Porygon", [65, 60, 70, 40, 85, 75], GENDERLESS, ["Trace", "Download"], [Mineral, Mineral], MediumFast),
+    species(138, "Omanyte", [35, 40, 100, 35, 90, 55], 0x1f, ["Swift Swim", "Shell Armor"], [Water1, Water3], MediumFast),
+    species(139, "Omastar", [70, 60, 125, 55, 115, 70], 0x1f, ["Swift Swim", "Shell Armor"], [Water1, Water3], MediumFast),
+    species(140, "Kabuto", [30, 80, 90, 55, 55, 45], 0x1f, ["Swift Swim", "Battle Armor"], [Water1, Water3], MediumFast),
+    species(141, "Kabutops", [60, 115, 105, 80, 65, 70], 0x1f, ["Swift Swim", "Battle Armor"], [Water1, Water3], MediumFast),
+    species(142, "Aerodactyl", [80, 105, 65, 130, 60, 75], 0x1f, ["Rock Head", "Pressure"], [Flying, Flying], Slow),
+    species(143, "Snorlax", [160, 110, 65, 30, 65, 110], 0x1f, ["Immunity", "Thick Fat"], [Monster, Monster], Slow),
+    species(144, "Articuno", [90, 85, 100, 85, 95, 125], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(145, "Zapdos", [90, 90, 85, 100, 125, 90], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(146, "Moltres", [90, 100, 90, 90, 125, 85], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(147, "Dratini", [41, 64, 45, 50, 50, 50], 0x7f, ["Shed Skin", "Shed Skin"], [Water1, Dragon], Slow),
+    species(148, "Dragonair", [61, 84, 65, 70, 70, 70], 0x7f, ["Shed Skin", "Shed Skin"], [Water1, Dragon], Slow),
+    species(149, "Dragonite", [91, 134, 95, 80, 100, 100], 0x7f, ["Inner Focus", "Inner Focus"], [Water1, Dragon], Slow),
+    species(150, "Mewtwo", [106, 110, 90, 130, 154, 90], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(151, "Mew", [100, 100, 100, 100, 100, 100], GENDERLESS, ["Synchronize", "Synchronize"], [Undiscovered, Undiscovered], MediumSlow),
+    species(152, "Chikorita", [45, 49, 65, 45, 49, 65], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(153, "Bayleef", [60, 62, 80, 60, 63, 80], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(154, "Meganium", [80, 82, 100, 80, 83, 100], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(155, "Cyndaquil", [39, 52, 43, 65, 60, 50], 0x1f, ["Blaze", "Blaze"], [Field, Field], MediumSlow),
+    species(156, "Quilava", [58, 64, 58, 80, 80, 65], 0x1f, ["Blaze", "Blaze"], [Field, Field], MediumSlow),
+    species(157, "Typhlosion", [78, 84, 78, 100, 109, 85], 0x1f, ["Blaze", "Blaze"], [Field, Field], MediumSlow),
+    species(158, "Totodile", [50, 65, 64, 43, 44, 48], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(159, "Croconaw", [65, 80, 80, 58, 59, 63], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(160, "Feraligatr", [85, 105, 100, 78, 79, 83], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(161, "Sentret", [35, 46, 34, 20, 35, 45], 0x7f, ["Run Away", "Keen Eye"], [Field, Field], MediumFast),
+    species(162, "Furret", [85, 76, 64, 90, 45, 55], 0x7f, ["Run Away", "Keen Eye"], [Field, Field], MediumFast),
+    species(163, "Hoothoot", [60, 30, 30, 50, 36, 56], 0x7f, ["Insomnia", "Keen Eye"], [Flying, Flying], MediumFast),
+    species(164, "Noctowl", [100, 50, 50, 70, 76, 96], 0x7f, ["Insomnia", "Keen Eye"], [Flying, Flying], MediumFast),
+    species(165, "Ledyba", [40, 20, 30, 55, 40, 80], 0x7f, ["Swarm", "Early Bird"], [Bug, Bug], Fast),
+    species(166, "Ledian", [55, 35, 50, 85, 55, 110], 0x7f, ["Swarm", "Early Bird"], [Bug, Bug], Fast),
+    species(167, "Spinarak", [40, 60, 40, 30, 40, 40], 0x7f, ["Swarm", "Insomnia"], [Bug, Bug], Fast),
+    species(168, "Ariados", [70, 90, 70, 40, 60, 60], 0x7f, ["Swarm", "Insomnia"], [Bug, Bug], Fast),
+    species(169, "Crobat", [85, 90, 80, 130, 70, 80], 0x7f, ["Inner Focus", "Inner Focus"], [Flying, Flying], MediumFast),
+    species(170, "Chinchou", [75, 38, 38, 67, 56, 56], 0x7f, ["Volt Absorb", "Illuminate"], [Water2, Water2], Slow),
+    species(171, "Lanturn", [125, 58, 58, 67, 76, 76], 0x7f, ["Volt Absorb", "Illuminate"], [Water2, Water2], Slow),
+    species(172, "Pichu", [20, 40, 15, 60, 35, 35], 0x7f, ["Static", "Static"], [Undiscovered, Undiscovered], MediumFast),
+    species(173, "Cleffa", [50, 25, 28, 15, 45, 55], 0xbf, ["Cute Charm", "Magic Guard"], [Undiscovered, Undiscovered], Fast),
+    species(174, "Igglybuff", [90, 30, 15, 15, 40, 20], 0xbf, ["Cute Charm", "Cute Charm"], [Undiscovered, Undiscovered], Fast),
+    species(175, "Togepi", [35, 20, 65, 20, 40, 65], 0x1f, ["Hustle", "Serene Grace"], [Undiscovered, Undiscovered], Fast),
+    species(176, "Togetic", [55, 40, 85, 40, 80, 105], 0x1f, ["Hustle", "Serene Grace"], [Flying, Fairy], Fast),
+    species(177, "Natu", [40, 50, 45, 70, 70, 45], 0x7f, ["Synchronize", "Early Bird"], [Flying, Flying], MediumFast),
+    species(178, "Xatu", [65, 75, 70, 95, 95, 70], 0x7f, ["Synchronize", "Early Bird"], [Flying, Flying], MediumFast),
+    species(179, "Mareep", [55, 40, 40, 35, 65, 45], 0x7f, ["Static", "Static"], [Monster, Field], MediumSlow),
+    species(180, "Flaaffy", [70, 55, 55, 45, 80, 60], 0x7f, ["Static", "Static"], [Monster, Field], MediumSlow),
+    species(181, "Ampharos", [90, 75, 75, 55, 115, 90], 0x7f, ["Static", "Static"], [Monster, Field], MediumSlow),
+    species(182, "Bellossom", [75, 80, 85, 50, 90, 100], 0x7f, ["Chlorophyll", "Chlorophyll"], [Grass, Grass], MediumSlow),
+    species(183, "Marill", [70, 20, 50, 40, 20, 50], 0x7f, ["Thick Fat", "Huge Power"], [Water1, Fairy], Fast),
+    species(184, "Azumarill", [100, 50, 80, 50, 50, 80], 0x7f, ["Thick Fat", "Huge Power"], [Water1, Fairy], Fast),
+    species(185, "Sudowoodo", [70, 100, 115, 30, 30, 65], 0x7f, ["Sturdy", "Rock Head"], [Mineral, Mineral], MediumFast),
+    species(186, "Politoed", [90, 75, 75, 70, 90, 100], 0x7f, ["Water Absorb", "Damp"], [Water1, Water1], MediumSlow),
+    species(187, "Hoppip", [35, 35, 40, 50, 35, 55], 0x7f, ["Chlorophyll", "Leaf Guard"], [Fairy, Grass], MediumSlow),
+    species(188, "Skiploom", [55, 45, 50, 80, 45, 65], 0x7f, ["Chlorophyll", "Leaf Guard"], [Fairy, Grass], MediumSlow),
+    species(189, "Jumpluff", [75, 55, 70, 110, 55, 85], 0x7f, ["Chlorophyll", "Leaf Guard"], [Fairy, Grass], MediumSlow),
+    species(190, "Aipom", [55, 70, 55, 85, 40, 55], 0x7f, ["Run Away", "Pickup"], [Field, Field], Fast),
+    species(191, "Sunkern", [30, 30, 30, 30, 30, 30], 0x7f, ["Chlorophyll", "Solar Power"], [Grass, Grass], MediumSlow),
+    species(192, "Sunflora", [75, 75, 55, 30, 105, 85], 0x7f, ["Chlorophyll", "Solar Power"], [Grass, Grass], MediumSlow),
+    species(193, "Yanma", [65, 65, 45, 95, 75, 45], 0x7f, ["Speed Boost", "Compound Eyes"], [Bug, Bug], MediumFast),
+    species(194, "Wooper", [55, 45, 45, 15, 25, 25], 0x7f, ["Damp", "Water Absorb"], [Water1, Field], MediumFast),
+    species(195, "Quagsire", [95, 85, 85, 35, 65, 65], 0x7f, ["Damp", "Water Absorb"], [Water1, Field], MediumFast),
+    species(196, "Espeon", [65, 65, 60, 110, 130, 95], 0x1f, ["Synchronize", "Synchronize"], [Field, Field], MediumFast),
+    species(197, "Umbreon", [95, 65, 110, 65, 60, 130], 0x1f, ["Synchronize", "Synchronize"], [Field, Field], MediumFast),
+    species(198, "Murkrow", [60, 85, 42, 91, 85, 42], 0x7f, ["Insomnia", "Super Luck"], [Flying, Flying], MediumSlow),
+    species(199, "Slowking", [95, 75, 80, 30, 100, 110], 0x7f, ["Oblivious", "Own Tempo"], [Monster, Water1], MediumFast),
+    species(200, "Misdreavus", [60, 60, 60, 85, 85, 85], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], Fast),
+    species(201, "Unown", [48, 72, 48, 48, 72, 48], GENDERLESS, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], MediumFast),
+    species(202, "Wobbuffet", [190, 33, 58, 33, 33, 58], 0x7f, ["Shadow Tag", "Shadow Tag"], [Amorphous, Amorphous], MediumFast),
+    species(203, "Girafarig", [70, 80, 65, 85, 90, 65], 0x7f, ["Inner Focus", "Early Bird"], [Field, Field], MediumFast),
+    species(204, "Pineco", [50, 65, 90, 15, 35, 35], 0x7f, ["Sturdy", "Sturdy"], [Bug, Bug], MediumFast),
+    species(205, "Forretress", [75, 90, 140, 40, 60, 60], 0x7f, ["Sturdy", "Sturdy"], [Bug, Bug], MediumFast),
+    species(206, "Dunsparce", [100, 70, 70, 45, 65, 65], 0x7f, ["Serene Grace", "Run Away"], [Field, Field], MediumFast),
+    species(207, "Gligar", [65, 75, 105, 85, 35, 65], 0x7f, ["Hyper Cutter", "Sand Veil"], [Bug, Bug], MediumSlow),
+    species(208, "Steelix", [75, 85, 200, 30, 55, 65], 0x7f, ["Rock Head", "Sturdy"], [Mineral, Mineral], MediumFast),
+    species(209, "Snubbull", [60, 80, 50, 30, 40, 40], 0xbf, ["Intimidate", "Run Away"], [Field, Fairy], Fast),
+    species(210, "Granbull", [90, 120, 75, 45, 60, 60], 0xbf, ["Intimidate", "Quick Feet"], [Field, Fairy], Fast),
+    species(211, "Qwilfish", [65, 95, 75, 85, 55, 55], 0x7f, ["Poison Point", "Swift Swim"], [Water2, Water2], MediumFast),
+    species(212, "Scizor", [70, 130, 100, 65, 55, 80], 0x7f, ["Swarm", "Technician"], [Bug, Bug], MediumFast),
+    species(213, "Shuckle", [20, 10, 230, 5, 10, 230], 0x7f, ["Sturdy", "Gluttony"], [Bug, Bug], MediumSlow),
+    species(214, "Heracross", [80, 125, 75, 85, 40, 95], 0x7f, ["Swarm", "Guts"], [Bug, Bug], Slow),
+    species(215, "Sneasel", [55, 95, 55, 115, 35, 75], 0x7f, ["Inner Focus", "Keen Eye"], [Field, Field], MediumSlow),
+    species(216, "Teddiursa", [60, 80, 50, 40, 50, 50], 0x7f, ["Pickup", "Quick Feet"], [Field, Field], MediumFast),
+    species(217, "Ursaring", [90, 130, 75, 55, 75, 75], 0x7f, ["Guts", "Quick Feet"], [Field, Field], MediumFast),
+    species(218, "Slugma", [40, 40, 40, 20, 70, 40], 0x7f, ["Magma Armor", "Flame Body"], [Amorphous, Amorphous], MediumFast),
+    species(219, "Magcargo", [50, 50, 120, 30, 80, 80], 0x7f, ["Magma Armor", "Flame Body"], [Amorphous, Amorphous], MediumFast),
+    species(220, "Swinub", [50, 50, 40, 50, 30, 30], 0x7f, ["Oblivious", "Snow Cloak"], [Field, Field], Slow),
+    species(221, "Piloswine", [100, 100, 80, 50, 60, 60], 0x7f, ["Oblivious", "Snow Cloak"], [Field, Field], Slow),
+    species(222, "Corsola", [55, 55, 85, 35, 65, 85], 0xbf, ["Hustle", "Natural Cure"], [Water1, Water3], Fast),
+    species(223, "Remoraid", [35, 65, 35, 65, 65, 35], 0x7f, ["Hustle", "Sniper"], [Water1, Water2], MediumFast),
+    species(224, "Octillery", [75, 105, 75, 45, 105, 75], 0x7f, ["Suction Cups", "Sniper"], [Water1, Water2], MediumFast),
+    species(225, "Delibird", [45, 55, 45, 75, 65, 45], 0x7f, ["Vital Spirit", "Hustle"], [Water1, Field], Fast),
+    species(226, "Mantine", [65, 40, 70, 70, 80, 140], 0x7f, ["Swift Swim", "Water Absorb"], [Water1, Water1], Slow),
+    species(227, "Skarmory", [65, 80, 140, 70, 40, 70], 0x7f, ["Keen Eye", "Sturdy"], [Flying, Flying], Slow),
+    species(228, "Houndour", [45, 60, 30, 65, 80, 50], 0x7f, ["Early Bird", "Flash Fire"], [Field, Field], Slow),
+    species(229, "Houndoom", [75, 90, 50, 95, 110, 80], 0x7f, ["Early Bird", "Flash Fire"], [Field, Field], Slow),
+    species(230, "Kingdra", [75, 95, 95, 85, 95, 95], 0x7f, ["Swift Swim", "Sniper"], [Water1, Dragon], MediumFast),
+    species(231, "Phanpy", [90, 60, 60, 40, 40, 40], 0x7f, ["Pickup", "Pickup"], [Field, Field], MediumFast),
+    species(232, "Donphan", [90, 120, 120, 50, 60, 60], 0x7f, ["Sturdy", "Sturdy"], [Field, Field], MediumFast),
+    species(233, "Porygon2", [85, 80, 90, 60, 105, 95], GENDERLESS, ["Trace", "Download"], [Mineral, Mineral], MediumFast),
+    species(234, "Stantler", [73, 95, 62, 85, 85, 65], 0x7f, ["Intimidate", "Frisk"], [Field, Field], Slow),
+    species(235, "Smeargle", [55, 20, 35, 75, 20, 45], 0x7f, ["Own Tempo", "Technician"], [Field, Field], Fast),
+    species(236, "Tyrogue", [35, 35, 35, 35, 35, 35], MALE_ONLY, ["Guts", "Steadfast"], [Undiscovered, Undiscovered], MediumFast),
+    species(237, "Hitmontop", [50, 95, 95, 70, 35, 110], MALE_ONLY, ["Intimidate", "Technician"], [HumanLike, HumanLike], MediumFast),
+    species(238, "Smoochum", [45, 30, 15, 65, 85, 65], FEMALE_ONLY, ["Oblivious", "Forewarn"], [Undiscovered, Undiscovered], MediumFast),
+    species(239, "Elekid", [45, 63, 37, 95, 65, 55], 0x3f, ["Static", "Static"], [Undiscovered, Undiscovered], MediumFast),
+    species(240, "Magby", [45, 75, 37, 83, 70, 55], 0x3f, ["Flame Body", "Flame Body"], [Undiscovered, Undiscovered], MediumFast),
+    species(241, "Miltank", [95, 80, 105, 100, 40, 70], FEMALE_ONLY, ["Thick Fat", "Scrappy"], [Field, Field], Slow),
+    species(242, "Blissey", [255, 10, 10, 55, 75, 135], FEMALE_ONLY, ["Natural Cure", "Serene Grace"], [Fairy, Fairy], Fast),
+    species(243, "Raikou", [90, 85, 75, 115, 115, 100], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(244, "Entei", [115, 115, 85, 100, 90, 75], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(245, "Suicune", [100, 75, 115, 85, 90, 115], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(246, "Larvitar", [50, 64, 50, 41, 45, 50], 0x7f, ["Guts", "Guts"], [Monster, Monster], Slow),
+    species(247, "Pupitar", [70, 84, 70, 51, 65, 70], 0x7f, ["Shed Skin", "Shed Skin"], [Monster, Monster], Slow),
+    species(248, "Tyranitar", [100, 134, 110, 61, 95, 100], 0x7f, ["Sand Stream", "Sand Stream"], [Monster, Monster], Slow),
+    species(249, "Lugia", [106, 90, 130, 110, 90, 154], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(250, "Ho-Oh", [106, 130, 90, 90, 110, 154], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(251, "Celebi", [100, 100, 100, 100, 100, 100], GENDERLESS, ["Natural Cure", "Natural Cure"], [Undiscovered, Undiscovered], MediumSlow),
+    species(252, "Treecko", [40, 45, 35, 70, 65, 55], 0x1f, ["Overgrow", "Overgrow"], [Monster, Dragon], MediumSlow),
+    species(253, "Grovyle", [50, 65, 45, 95, 85, 65], 0x1f, ["Overgrow", "Overgrow"], [Monster, Dragon], MediumSlow),
+    species(254, "Sceptile", [70, 85, 65, 120, 105, 85], 0x1f, ["Overgrow", "Overgrow"], [Monster, Dragon], MediumSlow),
+    species(255, "Torchic", [45, 60, 40, 45, 70, 50], 0x1f, ["Blaze", "Blaze"], [Field, Field], MediumSlow),
+    species(256, "Combusken", [60, 85, 60, 55, 85, 60], 0x1f, ["Blaze", "Blaze"], [Field, Field], MediumSlow),
+    species(257, "Blaziken", [80, 120, 70, 80, 110, 70], 0x1f, ["Blaze", "Blaze"], [Field, Field], MediumSlow),
+    species(258, "Mudkip", [50, 70, 50, 40, 50, 50], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(259, "Marshtomp", [70, 85, 70, 50, 60, 70], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(260, "Swampert", [100, 110, 90, 60, 85, 90], 0x1f, ["Torrent", "Torrent"], [Monster, Water1], MediumSlow),
+    species(261, "Poochyena", [35, 55, 35, 35, 30, 30], 0x7f, ["Run Away", "Quick Feet"], [Field, Field], MediumFast),
+    species(262, "Mightyena", [70, 90, 70, 70, 60, 60], 0x7f, ["Intimidate", "Quick Feet"], [Field, Field], MediumFast),
+    species(263, "Zigzagoon", [38, 30, 41, 60, 30, 41], 0x7f, ["Pickup", "Gluttony"], [Field, Field], MediumFast),
+    species(264, "Linoone", [78, 70, 61, 100, 50, 61], 0x7f, ["Pickup", "Gluttony"], [Field, Field], MediumFast),
+    species(265, "Wurmple", [45, 45, 35, 20, 20, 30], 0x7f, ["Shield Dust", "Shield Dust"], [Bug, Bug], MediumFast),
+    species(266, "Silcoon", [50, 35, 55, 15, 25, 25], 0x7f, ["Shed Skin", "Shed Skin"], [Bug, Bug], MediumFast),
+    species(267, "Beautifly", [60, 70, 50, 65, 90, 50], 0x7f, ["Swarm", "Swarm"], [Bug, Bug], MediumFast),
+    species(268, "Cascoon", [50, 35, 55, 15, 25, 25], 0x7f, ["Shed Skin", "Shed Skin"], [Bug, Bug], MediumFast),
+    species(269, "Dustox", [60, 50, 70, 65, 50, 90], 0x7f, ["Shield Dust", "Shield Dust"], [Bug, Bug], MediumFast),
+    species(270, "Lotad", [40, 30, 30, 30, 40, 50], 0x7f, ["Swift Swim", "Rain Dish"], [Water1, Grass], MediumSlow),
+    species(271, "Lombre", [60, 50, 50, 50, 60, 70], 0x7f, ["Swift Swim", "Rain Dish"], [Water1, Grass], MediumSlow),
+    species(272, "Ludicolo", [80, 70, 70, 70, 90, 100], 0x7f, ["Swift Swim", "Rain Dish"], [Water1, Grass], MediumSlow),
+    species(273, "Seedot", [40, 40, 50, 30, 30, 30], 0x7f, ["Chlorophyll", "Early Bird"], [Field, Grass], MediumSlow),
+    species(274, "Nuzleaf", [70, 70, 40, 60, 60, 40], 0x7f, ["Chlorophyll", "Early Bird"], [Field, Grass], MediumSlow),
+    species(275, "Shiftry", [90, 100, 60, 80, 90, 60], 0x7f, ["Chlorophyll", "Early Bird"], [Field, Grass], MediumSlow),
+    species(276, "Taillow", [40, 55, 30, 85, 30, 30], 0x7f, ["Guts", "Guts"], [Flying, Flying], MediumSlow),
+    species(277, "Swellow", [60, 85, 60, 125, 50, 50], 0x7f, ["Guts", "Guts"], [Flying, Flying], MediumSlow),
+    species(278, "Wingull", [40, 30, 30, 85, 55, 30], 0x7f, ["Keen Eye", "Keen Eye"], [Water1, Flying], MediumFast),
+    species(279, "Pelipper", [60, 50, 100, 65, 85, 70], 0x7f, ["Keen Eye", "Keen Eye"], [Water1, Flying], MediumFast),
+    species(280, "Ralts", [28, 25, 25, 40, 45, 35], 0x7f, ["Synchronize", "Trace"], [Amorphous, Amorphous], Slow),
+    species(281, "Kirlia", [38, 35, 35, 50, 65, 55], 0x7f, ["Synchronize", "Trace"], [Amorphous, Amorphous], Slow),
+    species(282, "Gardevoir", [68, 65, 65, 80, 125, 115], 0x7f, ["Synchronize", "Trace"], [Amorphous, Amorphous], Slow),
+    species(283, "Surskit", [40, 30, 32, 65, 50, 52], 0x7f, ["Swift Swim", "Swift Swim"], [Water1, Bug], MediumFast),
+    species(284, "Masquerain", [70, 60, 62, 60, 80, 82], 0x7f, ["Intimidate", "Intimidate"], [Water1, Bug], MediumFast),
+    species(285, "Shroomish", [60, 40, 60, 35, 40, 60], 0x7f, ["Effect Spore", "Poison Heal"], [Fairy, Grass], Fluctuating),
+    species(286, "Breloom", [60, 130, 80, 70, 60, 60], 0x7f, ["Effect Spore", "Poison Heal"], [Fairy, Grass], Fluctuating),
+    species(287, "Slakoth", [60, 60, 60, 30, 35, 35], 0x7f, ["Truant", "Truant"], [Field, Field], Slow),
+    species(288, "Vigoroth", [80, 80, 80, 90, 55, 55], 0x7f, ["Vital Spirit", "Vital Spirit"], [Field, Field], Slow),
+    species(289, "Slaking", [150, 160, 100, 100, 95, 65], 0x7f, ["Truant", "Truant"], [Field, Field], Slow),
+    species(290, "Nincada", [31, 45, 90, 40, 30, 30], 0x7f, ["Compound Eyes", "Compound Eyes"], [Bug, Bug], Erratic),
+    species(291, "Ninjask", [61, 90, 45, 160, 50, 50], 0x7f, ["Speed Boost", "Speed Boost"], [Bug, Bug], Erratic),
+    species(292, "Shedinja", [1, 90, 45, 40, 30, 30], GENDERLESS, ["Wonder Guard", "Wonder Guard"], [Mineral, Mineral], Erratic),
+    species(293, "Whismur", [64, 51, 23, 28, 51, 23], 0x7f, ["Soundproof", "Soundproof"], [Monster, Field], MediumSlow),
+    species(294, "Loudred", [84, 71, 43, 48, 71, 43], 0x7f, ["Soundproof", "Soundproof"], [Monster, Field], MediumSlow),
+    species(295, "Exploud", [104, 91, 63, 68, 91, 63], 0x7f, ["Soundproof", "Soundproof"], [Monster, Field], MediumSlow),
+    species(296, "Makuhita", [72, 60, 30, 25, 20, 30], 0x7f, ["Thick Fat", "Guts"], [HumanLike, HumanLike], Fluctuating),
+    species(297, "Hariyama", [144, 120, 60, 50, 40, 60], 0x7f, ["Thick Fat", "Guts"], [HumanLike, HumanLike], Fluctuating),
+    species(298, "Azurill", [50, 20, 40, 20, 20, 40], 0xbf, ["Thick Fat", "Huge Power"], [Undiscovered, Undiscovered], Fast),
+    species(299, "Nosepass", [30, 45, 135, 30, 45, 90], 0x7f, ["Sturdy", "Magnet Pull"], [Mineral, Mineral], MediumFast),
+    species(300, "Skitty", [50, 45, 45, 50, 35, 35], 0xbf, ["Cute Charm", "Normalize"], [Field, Fairy], Fast),
+    species(301, "Delcatty", [70, 65, 65, 70, 55, 55], 0xbf, ["Cute Charm", "Normalize"], [Field, Fairy], Fast),
+    species(302, "Sableye", [50, 75, 75, 50, 65, 65], 0x7f, ["Keen Eye", "Stall"], [HumanLike, HumanLike], MediumSlow),
+    species(303, "Mawile", [50, 85, 85, 50, 55, 55], 0x7f, ["Hyper Cutter", "Intimidate"], [Field, Fairy], Fast),
+    species(304, "Aron", [50, 70, 100, 30, 40, 40], 0x7f, ["Sturdy", "Rock Head"], [Monster, Monster], Slow),
+    species(305, "Lairon", [60, 90, 140, 40, 50, 50], 0x7f, ["Sturdy", "Rock Head"], [Monster, Monster], Slow),
+    species(306, "Aggron", [70, 110, 180, 50, 60, 60], 0x7f, ["Sturdy", "Rock Head"], [Monster, Monster], Slow),
+    species(307, "Meditite", [30, 40, 55, 60, 40, 55], 0x7f, ["Pure Power", "Pure Power"], [HumanLike, HumanLike], MediumFast),
+    species(308, "Medicham", [60, 60, 75, 80, 60, 75], 0x7f, ["Pure Power", "Pure Power"], [HumanLike, HumanLike], MediumFast),
+    species(309, "Electrike", [40, 45, 40, 65, 65, 40], 0x7f, ["Static", "Lightning Rod"], [Field, Field], Slow),
+    species(310, "Manectric", [70, 75, 60, 105, 105, 60], 0x7f, ["Static", "Lightning Rod"], [Field, Field], Slow),
+    species(311, "Plusle", [60, 50, 40, 95, 85, 75], 0x7f, ["Plus", "Plus"], [Fairy, Fairy], MediumFast),
+    species(312, "Minun", [60, 40, 50, 95, 75, 85], 0x7f, ["Minus", "Minus"], [Fairy, Fairy], MediumFast),
+    species(313, "Volbeat", [65, 73, 55, 85, 47, 75], MALE_ONLY, ["Illuminate", "Swarm"], [Bug, HumanLike], Erratic),
+    species(314, "Illumise", [65, 47, 55, 85, 73, 75], FEMALE_ONLY, ["Oblivious", "Tinted Lens"], [Bug, HumanLike], Fluctuating),
+    species(315, "Roselia", [50, 60, 45, 65, 100, 80], 0x7f, ["Natural Cure", "Poison Point"], [Fairy, Grass], MediumSlow),
+    species(316, "Gulpin", [70, 43, 53, 40, 43, 53], 0x7f, ["Liquid Ooze", "Sticky Hold"], [Amorphous, Amorphous], Fluctuating),
+    species(317, "Swalot", [100, 73, 83, 55, 73, 83], 0x7f, ["Liquid Ooze", "Sticky Hold"], [Amorphous, Amorphous], Fluctuating),
+    species(318, "Carvanha", [45, 90, 20, 65, 65, 20], 0x7f, ["Rough Skin", "Rough Skin"], [Water2, Water2], Slow),
+    species(319, "Sharpedo", [70, 120, 40, 95, 95, 40], 0x7f, ["Rough Skin", "Rough Skin"], [Water2, Water2], Slow),
+    species(320, "Wailmer", [130, 70, 35, 60, 70, 35], 0x7f, ["Water Veil", "Oblivious"], [Field, Water2], Fluctuating),
+    species(321, "Wailord", [170, 90, 45, 60, 90, 45], 0x7f, ["Water Veil", "Oblivious"], [Field, Water2], Fluctuating),
+    species(322, "Numel", [60, 60, 40, 35, 65, 45], 0x7f, ["Oblivious", "Simple"], [Field, Field], MediumFast),
+    species(323, "Camerupt", [70, 100, 70, 40, 105, 75], 0x7f, ["Magma Armor", "Solid Rock"], [Field, Field], MediumFast),
+    species(324, "Torkoal", [70, 85, 140, 20, 85, 70], 0x7f, ["White Smoke", "White Smoke"], [Field, Field], MediumFast),
+    species(325, "Spoink", [60, 25, 35, 60, 70, 80], 0x7f, ["Thick Fat", "Own Tempo"], [Field, Field], Fast),
+    species(326, "Grumpig", [80, 45, 65, 80, 90, 110], 0x7f, ["Thick Fat", "Own Tempo"], [Field, Field], Fast),
+    species(327, "Spinda", [60, 60, 60, 60, 60, 60], 0x7f, ["Own Tempo", "Tangled Feet"], [Field, HumanLike], Fast),
+    species(328, "Trapinch", [45, 100, 45, 10, 45, 45], 0x7f, ["Hyper Cutter", "Arena Trap"], [Bug, Bug], MediumSlow),
+    species(329, "Vibrava", [50, 70, 50, 70, 50, 50], 0x7f, ["Levitate", "Levitate"], [Bug, Bug], MediumSlow),
+    species(330, "Flygon", [80, 100, 80, 100, 80, 80], 0x7f, ["Levitate", "Levitate"], [Bug, Bug], MediumSlow),
+    species(331, "Cacnea", [50, 85, 40, 35, 85, 40], 0x7f, ["Sand Veil", "Sand Veil"], [Grass, HumanLike], MediumSlow),
+    species(332, "Cacturne", [70, 115, 60, 55, 115, 60], 0x7f, ["Sand Veil", "Sand Veil"], [Grass, HumanLike], MediumSlow),
+    species(333, "Swablu", [45, 40, 60, 50, 40, 75], 0x7f, ["Natural Cure", "Natural Cure"], [Flying, Dragon], Erratic),
+    species(334, "Altaria", [75, 70, 90, 80, 70, 105], 0x7f, ["Natural Cure", "Natural Cure"], [Flying, Dragon], Erratic),
+    species(335, "Zangoose", [73, 115, 60, 90, 60, 60], 0x7f, ["Immunity", "Immunity"], [Field, Field], Erratic),
+    species(336, "Seviper", [73, 100, 60, 65, 100, 60], 0x7f, ["Shed Skin", "Shed Skin"], [Field, Dragon], Fluctuating),
+    species(337, "Lunatone", [70, 55, 65, 70, 95, 85], GENDERLESS, ["Levitate", "Levitate"], [Mineral, Mineral], Fast),
+    species(338, "Solrock", [70, 95, 85, 70, 55, 65], GENDERLESS, ["Levitate", "Levitate"], [Mineral, Mineral], Fast),
+    species(339, "Barboach", [50, 48, 43, 60, 46, 41], 0x7f, ["Oblivious", "Anticipation"], [Water2, Water2], MediumFast),
+    species(340, "Whiscash", [110, 78, 73, 60, 76, 71], 0x7f, ["Oblivious", "Anticipation"], [Water2, Water2], MediumFast),
+    species(341, "Corphish", [43, 80, 65, 35, 50, 35], 0x7f, ["Hyper Cutter", "Shell Armor"], [Water1, Water3], Fluctuating),
+    species(342, "Crawdaunt", [63, 120, 85, 55, 90, 55], 0x7f, ["Hyper Cutter", "Shell Armor"], [Water1, Water3], Fluctuating),
+    species(343, "Baltoy", [40, 40, 55, 55, 40, 70], GENDERLESS, ["Levitate", "Levitate"], [Mineral, Mineral], MediumFast),
+    species(344, "Claydol", [60, 70, 105, 75, 70, 120], GENDERLESS, ["Levitate", "Levitate"], [Mineral, Mineral], MediumFast),
+    species(345, "Lileep", [66, 41, 77, 23, 61, 87], 0x1f, ["Suction Cups", "Suction Cups"], [Water3, Water3], Erratic),
+    species(346, "Cradily", [86, 81, 97, 43, 81, 107], 0x1f, ["Suction Cups", "Suction Cups"], [Water3, Water3], Erratic),
+    species(347, "Anorith", [45, 95, 50, 75, 40, 50], 0x1f, ["Battle Armor", "Battle Armor"], [Water3, Water3], Erratic),
+    species(348, "Armaldo", [75, 125, 100, 45, 70, 80], 0x1f, ["Battle Armor", "Battle Armor"], [Water3, Water3], Erratic),
+    species(349, "Feebas", [20, 15, 20, 80, 10, 55], 0x7f, ["Swift Swim", "Oblivious"], [Water1, Dragon], Erratic),
+    species(350, "Milotic", [95, 60, 79, 81, 100, 125], 0x7f, ["Marvel Scale", "Marvel Scale"], [Water1, Dragon], Erratic),
+    species(351, "Castform", [70, 70, 70, 70, 70, 70], 0x7f, ["Forecast", "Forecast"], [Fairy, Amorphous], MediumFast),
+    species(352, "Kecleon", [60, 90, 70, 40, 60, 120], 0x7f, ["Color Change", "Color Change"], [Field, Field], MediumSlow),
+    species(353, "Shuppet", [44, 75, 35, 45, 63, 33], 0x7f, ["Insomnia", "Frisk"], [Amorphous, Amorphous], Fast),
+    species(354, "Banette", [64, 115, 65, 65, 83, 63], 0x7f, ["Insomnia", "Frisk"], [Amorphous, Amorphous], Fast),
+    species(355, "Duskull", [20, 40, 90, 25, 30, 90], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], Fast),
+    species(356, "Dusclops", [40, 70, 130, 25, 60, 130], 0x7f, ["Pressure", "Pressure"], [Amorphous, Amorphous], Fast),
+    species(357, "Tropius", [99, 68, 83, 51, 72, 87], 0x7f, ["Chlorophyll", "Solar Power"], [Monster, Grass], Slow),
+    species(358, "Chimecho", [65, 50, 70, 65, 95, 80], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], Fast),
+    species(359, "Absol", [65, 130, 60, 75, 75, 60], 0x7f, ["Pressure", "Super Luck"], [Field, Field], MediumSlow),
+    species(360, "Wynaut", [95, 23, 48, 23, 23, 48], 0x7f, ["Shadow Tag", "Shadow Tag"], [Undiscovered, Undiscovered], MediumFast),
+    species(361, "Snorunt", [50, 50, 50, 50, 50, 50], 0x7f, ["Inner Focus", "Ice Body"], [Fairy, Mineral], MediumFast),
+    species(362, "Glalie", [80, 80, 80, 80, 80, 80], 0x7f, ["Inner Focus", "Ice Body"], [Fairy, Mineral], MediumFast),
+    species(363, "Spheal", [70, 40, 50, 25, 55, 50], 0x7f, ["Thick Fat", "Ice Body"], [Water1, Field], MediumSlow),
+    species(364, "Sealeo", [90, 60, 70, 45, 75, 70], 0x7f, ["Thick Fat", "Ice Body"], [Water1, Field], MediumSlow),
+    species(365, "Walrein", [110, 80, 90, 65, 95, 90], 0x7f, ["Thick Fat", "Ice Body"], [Water1, Field], MediumSlow),
+    species(366, "Clamperl", [35, 64, 85, 32, 74, 55], 0x7f, ["Shell Armor", "Shell Armor"], [Water1, Water1], Erratic),
+    species(367, "Huntail", [55, 104, 105, 52, 94, 75], 0x7f, ["Swift Swim", "Swift Swim"], [Water1, Water1], Erratic),
+    species(368, "Gorebyss", [55, 84, 105, 52, 114, 75], 0x7f, ["Swift Swim", "Swift Swim"], [Water1, Water1], Erratic),
+    species(369, "Relicanth", [100, 90, 130, 55, 45, 65], 0x7f, ["Swift Swim", "Rock Head"], [Water1, Water2], Slow),
+    species(370, "Luvdisc", [43, 30, 55, 97, 40, 65], 0xbf, ["Swift Swim", "Swift Swim"], [Water2, Water2], Fast),
+    species(371, "Bagon", [45, 75, 60, 50, 40, 30], 0x7f, ["Rock Head", "Rock Head"], [Dragon, Dragon], Slow),
+    species(372, "Shelgon", [65, 95, 100, 50, 60, 50], 0x7f, ["Rock Head", "Rock Head"], [Dragon, Dragon], Slow),
+    species(373, "Salamence", [95, 135, 80, 100, 110, 80], 0x7f, ["Intimidate", "Intimidate"], [Dragon, Dragon], Slow),
+    species(374, "Beldum", [40, 55, 80, 30, 35, 60], GENDERLESS, ["Clear Body", "Clear Body"], [Mineral, Mineral], Slow),
+    species(375, "Metang", [60, 75, 100, 50, 55, 80], GENDERLESS, ["Clear Body", "Clear Body"], [Mineral, Mineral], Slow),
+    species(376, "Metagross", [80, 135, 130, 70, 95, 90], GENDERLESS, ["Clear Body", "Clear Body"], [Mineral, Mineral], Slow),
+    species(377, "Regirock", [80, 100, 200, 50, 50, 100], GENDERLESS, ["Clear Body", "Clear Body"], [Undiscovered, Undiscovered], Slow),
+    species(378, "Regice", [80, 50, 100, 50, 100, 200], GENDERLESS, ["Clear Body", "Clear Body"], [Undiscovered, Undiscovered], Slow),
+    species(379, "Registeel", [80, 75, 150, 50, 75, 150], GENDERLESS, ["Clear Body", "Clear Body"], [Undiscovered, Undiscovered], Slow),
+    species(380, "Latias", [80, 80, 90, 110, 110, 130], FEMALE_ONLY, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Slow),
+    species(381, "Latios", [80, 90, 80, 110, 130, 110], MALE_ONLY, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Slow),
+    species(382, "Kyogre", [100, 100, 90, 90, 150, 140], GENDERLESS, ["Drizzle", "Drizzle"], [Undiscovered, Undiscovered], Slow),
+    species(383, "Groudon", [100, 150, 140, 90, 100, 90], GENDERLESS, ["Drought", "Drought"], [Undiscovered, Undiscovered], Slow),
+    species(384, "Rayquaza", [105, 150, 90, 95, 150, 90], GENDERLESS, ["Air Lock", "Air Lock"], [Undiscovered, Undiscovered], Slow),
+    species(385, "Jirachi", [100, 100, 100, 100, 100, 100], GENDERLESS, ["Serene Grace", "Serene Grace"], [Undiscovered, Undiscovered], Slow),
+    species(386, "Deoxys", [50, 150, 50, 150, 150, 50], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(387, "Turtwig", [55, 68, 64, 31, 45, 55], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(388, "Grotle", [75, 89, 85, 36, 55, 65], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(389, "Torterra", [95, 109, 105, 56, 75, 85], 0x1f, ["Overgrow", "Overgrow"], [Monster, Grass], MediumSlow),
+    species(390, "Chimchar", [44, 58, 44, 61, 58, 44], 0x1f, ["Blaze", "Blaze"], [Field, HumanLike], MediumSlow),
+    species(391, "Monferno", [64, 78, 52, 81, 78, 52], 0x1f, ["Blaze", "Blaze"], [Field, HumanLike], MediumSlow),
+    species(392, "Infernape", [76, 104, 71, 108, 104, 71], 0x1f, ["Blaze", "Blaze"], [Field, HumanLike], MediumSlow),
+    species(393, "Piplup", [53, 51, 53, 40, 61, 56], 0x1f, ["Torrent", "Torrent"], [Water1, Field], MediumSlow),
+    species(394, "Prinplup", [64, 66, 68, 50, 81, 76], 0x1f, ["Torrent", "Torrent"], [Water1, Field], MediumSlow),
+    species(395, "Empoleon", [84, 86, 88, 60, 111, 101], 0x1f, ["Torrent", "Torrent"], [Water1, Field], MediumSlow),
+    species(396, "Starly", [40, 55, 30, 60, 30, 30], 0x7f, ["Keen Eye", "Keen Eye"], [Flying, Flying], MediumSlow),
+    species(397, "Staravia", [55, 75, 50, 80, 40, 40], 0x7f, ["Intimidate", "Intimidate"], [Flying, Flying], MediumSlow),
+    species(398, "Staraptor", [85, 120, 70, 100, 50, 50], 0x7f, ["Intimidate", "Intimidate"], [Flying, Flying], MediumSlow),
+    species(399, "Bidoof", [59, 45, 40, 31, 35, 40], 0x7f, ["Simple", "Unaware"], [Water1, Field], MediumFast),
+    species(400, "Bibarel", [79, 85, 60, 71, 55, 60], 0x7f, ["Simple", "Unaware"], [Water1, Field], MediumFast),
+    species(401, "Kricketot", [37, 25, 41, 25, 25, 41], 0x7f, ["Shed Skin", "Shed Skin"], [Bug, Bug], MediumSlow),
+    species(402, "Kricketune", [77, 85, 51, 65, 55, 51], 0x7f, ["Swarm", "Swarm"], [Bug, Bug], MediumSlow),
+    species(403, "Shinx", [45, 65, 34, 45, 40, 34], 0x7f, ["Rivalry", "Intimidate"], [Field, Field], MediumSlow),
+    species(404, "Luxio", [60, 85, 49, 60, 60, 49], 0x7f, ["Rivalry", "Intimidate"], [Field, Field], MediumSlow),
+    species(405, "Luxray", [80, 120, 79, 70, 95, 79], 0x7f, ["Rivalry", "Intimidate"], [Field, Field], MediumSlow),
+    species(406, "Budew", [40, 30, 35, 55, 50, 70], 0x7f, ["Natural Cure", "Poison Point"], [Undiscovered, Undiscovered], MediumSlow),
+    species(407, "Roserade", [60, 70, 55, 90, 125, 105], 0x7f, ["Natural Cure", "Poison Point"], [Fairy, Grass], MediumSlow),
+    species(408, "Cranidos", [67, 125, 40, 58, 30, 30], 0x1f, ["Mold Breaker", "Mold Breaker"], [Monster, Monster], Erratic),
+    species(409, "Rampardos", [97, 165, 60, 58, 65, 50], 0x1f, ["Mold Breaker", "Mold Breaker"], [Monster, Monster], Erratic),
+    species(410, "Shieldon", [30, 42, 118, 30, 42, 88], 0x1f, ["Sturdy", "Sturdy"], [Monster, Monster], Erratic),
+    species(411, "Bastiodon", [60, 52, 168, 30, 47, 138], 0x1f, ["Sturdy", "Sturdy"], [Monster, Monster], Erratic),
+    species(412, "Burmy", [40, 29, 45, 36, 29, 45], 0x7f, ["Shed Skin", "Shed Skin"], [Bug, Bug], MediumFast),
+    species(413, "Wormadam", [60, 59, 85, 36, 79, 105], FEMALE_ONLY, ["Anticipation", "Anticipation"], [Bug, Bug], MediumFast),
+    species(414, "Mothim", [70, 94, 50, 66, 94, 50], MALE_ONLY, ["Swarm", "Swarm"], [Bug, Bug], MediumFast),
+    species(415, "Combee", [30, 30, 42, 70, 30, 42], 0x1f, ["Honey Gather", "Honey Gather"], [Bug, Bug], MediumSlow),
+    species(416, "Vespiquen", [70, 80, 102, 40, 80, 102], FEMALE_ONLY, ["Pressure", "Pressure"], [Bug, Bug], MediumSlow),
+    species(417, "Pachirisu", [60, 45, 70, 95, 45, 90], 0x7f, ["Run Away", "Pickup"], [Field, Fairy], MediumFast),
+    species(418, "Buizel", [55, 65, 35, 85, 60, 30], 0x7f, ["Swift Swim", "Swift Swim"], [Water1, Field], MediumFast),
+    species(419, "Floatzel", [85, 105, 55, 115, 85, 50], 0x7f, ["Swift Swim", "Swift Swim"], [Water1, Field], MediumFast),
+    species(420, "Cherubi", [45, 35, 45, 35, 62, 53], 0x7f, ["Chlorophyll", "Chlorophyll"], [Fairy, Grass], MediumFast),
+    species(421, "Cherrim", [70, 60, 70, 85, 87, 78], 0x7f, ["Flower Gift", "Flower Gift"], [Fairy, Grass], MediumFast),
+    species(422, "Shellos", [76, 48, 48, 34, 57, 62], 0x7f, ["Sticky Hold", "Storm Drain"], [Water1, Amorphous], MediumFast),
+    species(423, "Gastrodon", [111, 83, 68, 39, 92, 82], 0x7f, ["Sticky Hold", "Storm Drain"], [Water1, Amorphous], MediumFast),
+    species(424, "Ambipom", [75, 100, 66, 115, 60, 66], 0x7f, ["Technician", "Pickup"], [Field, Field], Fast),
+    species(425, "Drifloon", [90, 50, 34, 70, 60, 44], 0x7f, ["Aftermath", "Unburden"], [Amorphous, Amorphous], Fluctuating),
+    species(426, "Drifblim", [150, 80, 44, 80, 90, 54], 0x7f, ["Aftermath", "Unburden"], [Amorphous, Amorphous], Fluctuating),
+    species(427, "Buneary", [55, 66, 44, 85, 44, 56], 0x7f, ["Run Away", "Klutz"], [Field, HumanLike], MediumFast),
+    species(428, "Lopunny", [65, 76, 84, 105, 54, 96], 0x7f, ["Cute Charm", "Klutz"], [Field, HumanLike], MediumFast),
+    species(429, "Mismagius", [60, 60, 60, 105, 105, 105], 0x7f, ["Levitate", "Levitate"], [Amorphous, Amorphous], Fast),
+    species(430, "Honchkrow", [100, 125, 52, 71, 105, 52], 0x7f, ["Insomnia", "Super Luck"], [Flying, Flying], MediumSlow),
+    species(431, "Glameow", [49, 55, 42, 85, 42, 37], 0xbf, ["Limber", "Own Tempo"], [Field, Field], Fast),
+    species(432, "Purugly", [71, 82, 64, 112, 64, 59], 0xbf, ["Thick Fat", "Own Tempo"], [Field, Field], Fast),
+    species(433, "Chingling", [45, 30, 50, 45, 65, 50], 0x7f, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Fast),
+    species(434, "Stunky", [63, 63, 47, 74, 41, 41], 0x7f, ["Stench", "Aftermath"], [Field, Field], MediumFast),
+    species(435, "Skuntank", [103, 93, 67, 84, 71, 61], 0x7f, ["Stench", "Aftermath"], [Field, Field], MediumFast),
+    species(436, "Bronzor", [57, 24, 86, 23, 24, 86], GENDERLESS, ["Levitate", "Heatproof"], [Mineral, Mineral], MediumFast),
+    species(437, "Bronzong", [67, 89, 116, 33, 79, 116], GENDERLESS, ["Levitate", "Heatproof"], [Mineral, Mineral], MediumFast),
+    species(438, "Bonsly", [50, 80, 95, 10, 10, 45], 0x7f, ["Sturdy", "Rock Head"], [Undiscovered, Undiscovered], MediumFast),
+    species(439, "Mime Jr.", [20, 25, 45, 60, 70, 90], 0x7f, ["Soundproof", "Filter"], [Undiscovered, Undiscovered], MediumFast),
+    species(440, "Happiny", [100, 5, 5, 30, 15, 65], FEMALE_ONLY, ["Natural Cure", "Serene Grace"], [Undiscovered, Undiscovered], Fast),
+    species(441, "Chatot", [76, 65, 45, 91, 92, 42], 0x7f, ["Keen Eye", "Tangled Feet"], [Flying, Flying], MediumSlow),
+    species(442, "Spiritomb", [50, 92, 108, 35, 92, 108], 0x7f, ["Pressure", "Pressure"], [Amorphous, Amorphous], MediumFast),
+    species(443, "Gible", [58, 70, 45, 42, 40, 45], 0x7f, ["Sand Veil", "Sand Veil"], [Monster, Dragon], Slow),
+    species(444, "Gabite", [68, 90, 65, 82, 50, 55], 0x7f, ["Sand Veil", "Sand Veil"], [Monster, Dragon], Slow),
+    species(445, "Garchomp", [108, 130, 95, 102, 80, 85], 0x7f, ["Sand Veil", "Sand Veil"], [Monster, Dragon], Slow),
+    species(446, "Munchlax", [135, 85, 40, 5, 40, 85], 0x1f, ["Pickup", "Thick Fat"], [Undiscovered, Undiscovered], Slow),
+    species(447, "Riolu", [40, 70, 40, 60, 35, 40], 0x1f, ["Steadfast", "Inner Focus"], [Undiscovered, Undiscovered], MediumSlow),
+    species(448, "Lucario", [70, 110, 70, 90, 115, 70], 0x1f, ["Steadfast", "Inner Focus"], [Field, HumanLike], MediumSlow),
+    species(449, "Hippopotas", [68, 72, 78, 32, 38, 42], 0x7f, ["Sand Stream", "Sand Stream"], [Field, Field], Slow),
+    species(450, "Hippowdon", [108, 112, 118, 47, 68, 72], 0x7f, ["Sand Stream", "Sand Stream"], [Field, Field], Slow),
+    species(451, "Skorupi", [40, 50, 90, 65, 30, 55], 0x7f, ["Battle Armor", "Sniper"], [Bug, Water3], Slow),
+    species(452, "Drapion", [70, 90, 110, 95, 60, 75], 0x7f, ["Battle Armor", "Sniper"], [Bug, Water3], Slow),
+    species(453, "Croagunk", [48, 61, 40, 50, 61, 40], 0x7f, ["Anticipation", "Dry Skin"], [HumanLike, HumanLike], MediumFast),
+    species(454, "Toxicroak", [83, 106, 65, 85, 86, 65], 0x7f, ["Anticipation", "Dry Skin"], [HumanLike, HumanLike], MediumFast),
+    species(455, "Carnivine", [74, 100, 72, 46, 90, 72], 0x7f, ["Levitate", "Levitate"], [Grass, Grass], Slow),
+    species(456, "Finneon", [49, 49, 56, 66, 49, 61], 0x7f, ["Swift Swim", "Storm Drain"], [Water2, Water2], Erratic),
+    species(457, "Lumineon", [69, 69, 76, 91, 69, 86], 0x7f, ["Swift Swim", "Storm Drain"], [Water2, Water2], Erratic),
+    species(458, "Mantyke", [45, 20, 50, 50, 60, 120], 0x7f, ["Swift Swim", "Water Absorb"], [Undiscovered, Undiscovered], Slow),
+    species(459, "Snover", [60, 62, 50, 40, 62, 60], 0x7f, ["Snow Warning", "Snow Warning"], [Monster, Grass], Slow),
+    species(460, "Abomasnow", [90, 92, 75, 60, 92, 85], 0x7f, ["Snow Warning", "Snow Warning"], [Monster, Grass], Slow),
+    species(461, "Weavile", [70, 120, 65, 125, 45, 85], 0x7f, ["Pressure", "Pressure"], [Field, Field], MediumSlow),
+    species(462, "Magnezone", [70, 70, 115, 60, 130, 90], GENDERLESS, ["Magnet Pull", "Sturdy"], [Mineral, Mineral], MediumFast),
+    species(463, "Lickilicky", [110, 85, 95, 50, 80, 95], 0x7f, ["Own Tempo", "Oblivious"], [Monster, Monster], MediumFast),
+    species(464, "Rhyperior", [115, 140, 130, 40, 55, 55], 0x7f, ["Lightning Rod", "Solid Rock"], [Monster, Field], Slow),
+    species(465, "Tangrowth", [100, 100, 125, 50, 110, 50], 0x7f, ["Chlorophyll", "Leaf Guard"], [Grass, Grass], MediumFast),
+    species(466, "Electivire", [75, 123, 67, 95, 95, 85], 0x3f, ["Motor Drive", "Motor Drive"], [HumanLike, HumanLike], MediumFast),
+    species(467, "Magmortar", [75, 95, 67, 83, 125, 95], 0x3f, ["Flame Body", "Flame Body"], [HumanLike, HumanLike], MediumFast),
+    species(468, "Togekiss", [85, 50, 95, 80, 120, 115], 0x1f, ["Hustle", "Serene Grace"], [Flying, Fairy], Fast),
+    species(469, "Yanmega", [86, 76, 86, 95, 116, 56], 0x7f, ["Speed Boost", "Tinted Lens"], [Bug, Bug], MediumFast),
+    species(470, "Leafeon", [65, 110, 130, 95, 60, 65], 0x1f, ["Leaf Guard", "Leaf Guard"], [Field, Field], MediumFast),
+    species(471, "Glaceon", [65, 60, 110, 65, 130, 95], 0x1f, ["Snow Cloak", "Snow Cloak"], [Field, Field], MediumFast),
+    species(472, "Gliscor", [75, 95, 125, 95, 45, 75], 0x7f, ["Hyper Cutter", "Sand Veil"], [Bug, Bug], MediumSlow),
+    species(473, "Mamoswine", [110, 130, 80, 80, 70, 60], 0x7f, ["Oblivious", "Snow Cloak"], [Field, Field], Slow),
+    species(474, "Porygon-Z", [85, 80, 70, 90, 135, 75], GENDERLESS, ["Adaptability", "Download"], [Mineral, Mineral], MediumFast),
+    species(475, "Gallade", [68, 125, 65, 80, 65, 115], MALE_ONLY, ["Steadfast", "Steadfast"], [Amorphous, Amorphous], Slow),
+    species(476, "Probopass", [60, 55, 145, 40, 75, 150], 0x7f, ["Sturdy", "Magnet Pull"], [Mineral, Mineral], MediumFast),
+    species(477, "Dusknoir", [45, 100, 135, 45, 65, 135], 0x7f, ["Pressure", "Pressure"], [Amorphous, Amorphous], Fast),
+    species(478, "Froslass", [70, 80, 70, 110, 80, 70], FEMALE_ONLY, ["Snow Cloak", "Snow Cloak"], [Fairy, Mineral], MediumFast),
+    species(479, "Rotom", [50, 50, 77, 91, 95, 77], GENDERLESS, ["Levitate", "Levitate"], [Amorphous, Amorphous], MediumFast),
+    species(480, "Uxie", [75, 75, 130, 95, 75, 130], GENDERLESS, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Slow),
+    species(481, "Mesprit", [80, 105, 105, 80, 105, 105], GENDERLESS, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Slow),
+    species(482, "Azelf", [75, 125, 70, 115, 125, 70], GENDERLESS, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Slow),
+    species(483, "Dialga", [100, 120, 120, 90, 150, 100], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(484, "Palkia", [90, 120, 100, 100, 150, 120], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(485, "Heatran", [91, 90, 106, 77, 130, 106], 0x7f, ["Flash Fire", "Flash Fire"], [Undiscovered, Undiscovered], Slow),
+    species(486, "Regigigas", [110, 160, 110, 100, 80, 110], GENDERLESS, ["Slow Start", "Slow Start"], [Undiscovered, Undiscovered], Slow),
+    species(487, "Giratina", [150, 100, 120, 90, 100, 120], GENDERLESS, ["Pressure", "Pressure"], [Undiscovered, Undiscovered], Slow),
+    species(488, "Cresselia", [120, 70, 120, 85, 75, 130], FEMALE_ONLY, ["Levitate", "Levitate"], [Undiscovered, Undiscovered], Slow),
+    species(489, "Phione", [80, 80, 80, 80, 80, 80], GENDERLESS, ["Hydration", "Hydration"], [Water1, Fairy], Slow),
+    species(490, "Manaphy", [100, 100, 100, 100, 100, 100], GENDERLESS, ["Hydration", "Hydration"], [Water1, Fairy], Slow),
+    species(491, "Darkrai", [70, 90, 90, 125, 135, 90], GENDERLESS, ["Bad Dreams", "Bad Dreams"], [Undiscovered, Undiscovered], Slow),
+    species(492, "Shaymin", [100, 100, 100, 100, 100, 100], GENDERLESS, ["Natural Cure", "Natural Cure"], [Undiscovered, Undiscovered], MediumSlow),
+    species(493, "Arceus", [120, 120, 120, 120, 120, 120], GENDERLESS, ["Multitype", "Multitype"], [Undiscovered, Undiscovered], Slow),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn species_test() {
+        for (i, species) in SPECIES.iter().enumerate() {
+            assert_eq!(species.dex as usize, i + 1);
+        }
+
+        let garchomp = by_name("garchomp").unwrap();
+        assert_eq!(garchomp.dex, 445);
+        assert_eq!(garchomp.base_stats.attack, 130);
+        assert_eq!(garchomp.egg_groups, [Monster, Dragon]);
+
+        assert_eq!(by_dex(201).unwrap().name, "Unown");
+        assert_eq!(by_dex(0).map(|species| species.dex), None);
+        assert_eq!(by_dex(494).map(|species| species.dex), None);
+
+        assert_eq!(gender_ratio(29), Some(FEMALE_ONLY));
+        assert_eq!(gender_ratio(81), Some(GENDERLESS));
+        assert_eq!(gender_ratio(0), None);
+        assert_eq!(gender_ratio(600), None);
+        assert_eq!(by_dex(143).unwrap().ability(1), "Thick Fat");
+    }
+}
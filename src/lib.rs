@@ -2,9 +2,49 @@ mod constants;
 mod modules;
 mod types;
 
+use constants::species;
+use modules::stat_calculator;
+use types::{pid_traits::PidTraits, stats::StatObservation};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 pub fn test() -> String {
     return "[WASM]: OK!".to_string();
 }
+
+/*
+    PID から分かる特徴 (ケムッソの進化先/パッチールのぶち/特性の番号) を返す。
+    species (全国図鑑番号) を渡した場合は、その種族に関係するものだけを返す。
+    アンノーンの形は第4世代では PID から決まらないので返さない (PID で決まるのは第3世代だけ)。
+*/
+#[wasm_bindgen]
+pub fn decode_pid_traits(pid: u32, species: Option<u16>) -> Result<JsValue, JsValue> {
+    let pid_traits = PidTraits::decode(pid, species);
+    return serde_wasm_bindgen::to_value(&pid_traits).map_err(|err| err.into());
+}
+
+/*
+    全国図鑑番号から種族データを返す (見つからなければ null)。
+*/
+#[wasm_bindgen]
+pub fn get_species_by_dex(dex: u16) -> Result<JsValue, JsValue> {
+    return serde_wasm_bindgen::to_value(&species::by_dex(dex)).map_err(|err| err.into());
+}
+
+/*
+    英語名から種族データを返す (見つからなければ null)。
+*/
+#[wasm_bindgen]
+pub fn get_species_by_name(name: &str) -> Result<JsValue, JsValue> {
+    return serde_wasm_bindgen::to_value(&species::by_name(name)).map_err(|err| err.into());
+}
+
+/*
+    種族/性格と確認した能力値 ({ level, stats, evs } の配列) から個体値の範囲を返す (矛盾する場合は null)。
+*/
+#[wasm_bindgen]
+pub fn calc_iv_ranges(species: u16, nature: u8, observations: JsValue) -> Result<JsValue, JsValue> {
+    let observations: Vec<StatObservation> = serde_wasm_bindgen::from_value(observations)?;
+    let iv_ranges = stat_calculator::calc_iv_ranges(species, nature, &observations);
+    return serde_wasm_bindgen::to_value(&iv_ranges).map_err(|err| err.into());
+}
@@ -0,0 +1,126 @@
+use crate::types::{egg::*, gender::*, iv::*, seed::*, version::*};
+
+use super::{
+    rng::Rng,
+    rng_lc::{RngLC, RngLCStream},
+};
+
+pub struct EggAnalyzer {
+    rng_lc: RngLC,
+}
+
+impl EggAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            rng_lc: RngLC::new(),
+        }
+    }
+
+    /*
+        タマゴの個体値の遺伝。
+        個体値2の次から 遺伝する能力 (% 6, % 5, % 4) -> 参照する親 (% 2) x 3 の順に乱数を消費する。
+        ivs: 乱数で決まった個体値 (hp, attack, defense, speed, sp_attack, sp_defense の順)
+        返り値は (遺伝後の個体値, 能力ごとの個体値の出どころ)。
+    */
+    pub fn inherit_ivs(
+        &self,
+        iv_2nd_seed: Seed,
+        ivs: [IV; 6],
+        parents_ivs: [[IV; 6]; 2],
+        version: GameVersion,
+    ) -> ([IV; 6], [IVSource; 6]) {
+        let mut rng = RngLCStream::new(self.rng_lc, iv_2nd_seed);
+        let locus_rands: [Rand; 3] = std::array::from_fn(|_| rng.next_rand());
+        let parent_rands: [Rand; 3] = std::array::from_fn(|_| rng.next_rand());
+
+        let mut ivs = ivs;
+        let mut sources = [IVSource::Random; 6];
+        let gene_loci = inherited_stats(locus_rands, version.has_inheritance_bug());
+
+        // 同じ能力が 2 回選ばれた場合は後の遺伝で上書きされる
+        for (gene_locus, parent_rand) in gene_loci.iter().zip(parent_rands.iter()) {
+            let parent_num = (parent_rand % 2) as usize; // 0 -> 先親 | 1 -> 後親
+            ivs[*gene_locus] = parents_ivs[parent_num][*gene_locus];
+            sources[*gene_locus] = match parent_num {
+                0 => IVSource::Parent0,
+                _ => IVSource::Parent1,
+            };
+        }
+
+        return (ivs, sources);
+    }
+}
+
+/*
+    タマゴの種族。ニドラン♀とイルミーゼのタマゴは性別値が ♂1/2 の閾値未満なら
+    そのまま、そうでなければニドラン♂/バルビートになる (性別はその種族で固定)。
+*/
+pub fn egg_species(species: u16, pid: PID) -> u16 {
+    let female = Gender::from_pid(pid, 0x7f) == Gender::Female;
+
+    return match species {
+        29 if !female => 32,   // ニドラン♀ -> ニドラン♂
+        314 if !female => 313, // イルミーゼ -> バルビート
+        _ => species,
+    };
+}
+
+/*
+    遺伝する能力の番号 (0: hp | 1: attack | 2: defense | 3: speed | 4: sp_attack | 5: sp_defense)
+    残りの能力の一覧から 乱数 % 残りの数 番目を選び、一覧から取り除く。
+    DPt は取り除く位置に選んだインデックスではなく能力の番号を使うため、
+    別の能力が取り除かれて同じ能力が再び選ばれることがある (遺伝する能力が 3 つ未満になる)。
+*/
+pub fn inherited_stats(locus_rands: [Rand; 3], inheritance_bug: bool) -> [usize; 3] {
+    let mut gene_loci: [usize; 6] = [0, 1, 2, 3, 4, 5];
+    let mut stats = [0usize; 3];
+
+    for (i, rand) in locus_rands.iter().enumerate() {
+        let index = (rand % (6 - i as Rand)) as usize;
+        let stat = gene_loci[index];
+        let removed = if inheritance_bug { stat } else { index };
+
+        // 一覧の長さは 6 - i から 5 - i に縮むので、範囲外の位置なら末尾が落ちるだけになる
+        for j in removed..(5 - i) {
+            gene_loci[j] = gene_loci[j + 1];
+        }
+
+        stats[i] = stat;
+    }
+
+    return stats;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inherited_stats_test() {
+        assert_eq!(inherited_stats([0, 0, 0], false), [0, 1, 2]);
+        assert_eq!(inherited_stats([0, 0, 0], true), [0, 1, 1]);
+        assert_eq!(inherited_stats([5, 4, 3], false), [5, 4, 3]);
+        assert_eq!(inherited_stats([5, 4, 3], true), [5, 4, 3]);
+        assert_eq!(inherited_stats([2, 2, 2], false), [2, 3, 4]);
+        assert_eq!(inherited_stats([2, 2, 2], true), [2, 3, 3]);
+
+        // 修正済みの HGSS では常に異なる 3 つの能力が選ばれる
+        for rands in itertools::iproduct!(0..6, 0..5, 0..4) {
+            let stats = inherited_stats([rands.0, rands.1, rands.2], false);
+            assert!(stats[0] != stats[1] && stats[1] != stats[2] && stats[0] != stats[2]);
+        }
+    }
+
+    #[test]
+    fn egg_species_test() {
+        assert_eq!(egg_species(29, 0x1234567e), 29);
+        assert_eq!(egg_species(29, 0x1234567f), 32);
+        assert_eq!(egg_species(314, 0x00000000), 314);
+        assert_eq!(egg_species(314, 0xffffffff), 313);
+        assert_eq!(egg_species(25, 0xffffffff), 25);
+
+        // 生まれた種族の性別は固定
+        assert_eq!(Gender::from_pid(0x1234567f, 0x00), Gender::Male);
+        assert_eq!(Gender::from_pid(0x1234567e, 0xfe), Gender::Female);
+    }
+}
@@ -1,6 +1,12 @@
+pub mod egg_analyzer;
+pub mod radar_analyzer;
 pub mod rand_analyzer;
+pub mod rng;
 pub mod rng_lc;
 pub mod rng_mt;
+pub mod roamer_analyzer;
 pub mod seed_analyzer;
 pub mod seed_checker;
 pub mod seed_searcher;
+pub mod stat_calculator;
+pub mod wild_analyzer;
@@ -0,0 +1,236 @@
+use crate::types::{lead::*, radar::*, seed::*, status::*, wild::*};
+
+use super::{
+    rng::Rng,
+    rng_lc::{RngLC, RngLCStream},
+    seed_analyzer::SeedAnalyzer,
+    wild_analyzer::WildAnalyzer,
+};
+
+// 距離ごとの、連鎖が続くくさむらになる確率 (%)
+const CONTINUE_RATES: [u16; 4] = [88, 68, 48, 28];
+
+// 色違いの確率は連鎖数 40 で頭打ち
+const MAX_CHAIN: u16 = 40;
+
+pub struct RadarAnalyzer {
+    rng_lc: RngLC,
+    seed_analyzer: SeedAnalyzer,
+    wild_analyzer: WildAnalyzer,
+}
+
+impl RadarAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            rng_lc: RngLC::new(),
+            seed_analyzer: SeedAnalyzer::new(),
+            wild_analyzer: WildAnalyzer::new(),
+        }
+    }
+
+    /*
+        DPPt のポケトレで揺れるくさむらを予測する。
+        距離 1 ~ 4 の順に、位置 (rand % (8 * ring)) -> 連鎖判定 (rand % 100 < CONTINUE_RATES) -> 光る判定 の乱数を消費する。
+        光る判定は rand <= 0xffff / (8200 - 連鎖数 * 200) で、連鎖数 40 で約 1/200 になる。
+        返り値は (くさむらの一覧, 消費後のシード)。
+    */
+    pub fn predict_patches(&self, seed: Seed, chain: u16) -> (Vec<RadarPatch>, Seed) {
+        let mut rng = RngLCStream::new(self.rng_lc, seed);
+        let mut patches: Vec<RadarPatch> = Vec::new();
+        let shiny_threshold = 0xffff / (8200 - chain.min(MAX_CHAIN) as u32 * 200);
+
+        for (i, continue_rate) in CONTINUE_RATES.iter().enumerate() {
+            let ring = i as u8 + 1;
+            let position = (rng.next_rand() % (8 * ring as u16)) as u8;
+            let continues = rng.next_rand() % 100 < *continue_rate;
+            let shiny = rng.next_rand() as u32 <= shiny_threshold;
+
+            patches.push(RadarPatch {
+                ring,
+                position,
+                continues,
+                shiny,
+            });
+        }
+
+        return (patches, rng.state());
+    }
+
+    /*
+        光るくさむらの個体 (連鎖色違いの PID)
+        PID下位の下位 3bit -> PID上位の下位 3bit -> PID下位の残り 13bit を 1bit ずつ -> 個体値1 -> 個体値2
+        PID上位の残りは色違いになるように tid ^ sid ^ PID下位 から決まる。
+    */
+    pub fn generate_chained_shiny_status(&self, origin_seed: Seed, tid: Rand, sid: Rand) -> Status {
+        let mut rng = RngLCStream::new(self.rng_lc, origin_seed);
+
+        let mut pid_low = (rng.next_rand() & 7) as PID;
+        let mut pid_high = (rng.next_rand() & 7) as PID;
+
+        for i in 3..16 {
+            pid_low |= ((rng.next_rand() & 1) as PID) << i;
+        }
+
+        pid_high |= ((tid ^ sid) as PID ^ pid_low) & 0xfff8;
+        let pid = pid_high << 16 | pid_low;
+
+        let iv_1st_rand = rng.next_rand();
+        let iv_2nd_rand = rng.next_rand();
+
+        return self
+            .seed_analyzer
+            .build_status(pid, iv_1st_rand, iv_2nd_rand, tid, sid);
+    }
+
+    /*
+        揺れたくさむらの個体。
+        光るくさむらなら連鎖色違い、そうでなければ出現スロットのない Method J (性格 -> PID) で生成する。
+    */
+    pub fn generate_radar_status(
+        &self,
+        origin_seed: Seed,
+        shiny: bool,
+        tid: Rand,
+        sid: Rand,
+    ) -> Status {
+        if shiny {
+            return self.generate_chained_shiny_status(origin_seed, tid, sid);
+        }
+
+        let wild_params = WildParams {
+            method: WildMethod::MethodJ,
+            encounter_type: EncounterType::Static,
+            area: None,
+        };
+
+        return self
+            .wild_analyzer
+            .generate_wild_status(origin_seed, &wild_params, Lead::None, tid, sid)
+            .status;
+    }
+
+    /*
+        initial_seed から 0..=max_advances 消費したそれぞれのフレームでポケトレを使ったときの結果を列挙する。
+    */
+    pub fn list_radar_frames(
+        &self,
+        initial_seed: InitialSeed,
+        max_advances: u32,
+        chain: u16,
+        tid: Rand,
+        sid: Rand,
+    ) -> Vec<RadarFrame> {
+        let mut frames: Vec<RadarFrame> = Vec::new();
+        let mut seed = initial_seed;
+
+        for advances in 0..=max_advances {
+            let (patches, encounter_seed) = self.predict_patches(seed, chain);
+            let shiny = patches.iter().any(|patch| patch.shiny);
+            let encounter = self.generate_radar_status(encounter_seed, shiny, tid, sid);
+
+            frames.push(RadarFrame {
+                advances,
+                patches,
+                encounter,
+            });
+            seed = self.rng_lc.next(seed);
+        }
+
+        return frames;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::iv::IVs;
+
+    #[test]
+    fn predict_patches_test() {
+        let radar_analyzer = RadarAnalyzer::new();
+        let seed: Seed = 0x67d7e44c;
+
+        // 乱数: dd43 7078 c2d9 | 3310 5cb5 17af | fdbd 642a 4ec2 | b3da 1f65 010a
+        let (patches, next_seed) = radar_analyzer.predict_patches(seed, 40);
+        let summary: Vec<(u8, u8, bool, bool)> = patches
+            .iter()
+            .map(|patch| (patch.ring, patch.position, patch.continues, patch.shiny))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, 3, false, false), // 92 < 88 ではないので続かない
+                (2, 0, true, false),
+                (3, 13, true, false),
+                (4, 26, false, true), // 0x010a = 266 <= 0xffff / 200 = 327
+            ]
+        );
+        assert_eq!(next_seed, 0x010adef8);
+        assert_eq!(next_seed, RngLC::new().advance(seed, 12u32));
+
+        // 連鎖数が少なければ光らず、40 を超えても確率は変わらない
+        let shiny = |chain| {
+            let (patches, _) = radar_analyzer.predict_patches(seed, chain);
+            patches.iter().any(|patch| patch.shiny)
+        };
+        assert!(!shiny(0));
+        assert!(!shiny(30));
+        assert!(shiny(40));
+        assert!(shiny(100));
+    }
+
+    #[test]
+    fn chained_shiny_test() {
+        let radar_analyzer = RadarAnalyzer::new();
+        let (tid, sid) = (12345, 54321);
+
+        // 乱数: 0b71 84ea d98a ... 2422 (PID) | b53a bfcd (個体値)
+        let status = radar_analyzer.generate_chained_shiny_status(0x12345678, tid, sid);
+        assert_eq!(status.pid, 0xc14a2541);
+        assert_eq!(
+            status.ivs,
+            IVs {
+                hp: 26,
+                attack: 9,
+                defense: 13,
+                speed: 13,
+                sp_attack: 30,
+                sp_defense: 15,
+            }
+        );
+        assert!(status.shiny);
+    }
+
+    #[test]
+    fn list_radar_frames_test() {
+        let radar_analyzer = RadarAnalyzer::new();
+        let wild_analyzer = WildAnalyzer::new();
+        let (tid, sid) = (12345, 54321);
+        let frames = radar_analyzer.list_radar_frames(0x12345678, 120, 40, tid, sid);
+
+        assert_eq!(frames.len(), 121);
+
+        // 光るくさむらがなければ Method J で生成した個体
+        assert!(frames[0].patches.iter().all(|patch| !patch.shiny));
+        let wild_params = WildParams {
+            method: WildMethod::MethodJ,
+            encounter_type: EncounterType::Static,
+            area: None,
+        };
+        let wild_status =
+            wild_analyzer.generate_wild_status(0x10e8f964, &wild_params, Lead::None, tid, sid);
+        assert_eq!(frames[0].encounter.pid, wild_status.status.pid);
+
+        // 116 消費で 0x67d7e44c になり、距離 4 のくさむらが光る
+        let frame = &frames[116];
+        assert_eq!(frame.advances, 116);
+        assert!(frame.patches[3].shiny);
+        assert!(frame.encounter.shiny);
+        assert_eq!(
+            frame.encounter.pid,
+            radar_analyzer
+                .generate_chained_shiny_status(0x010adef8, tid, sid)
+                .pid
+        );
+    }
+}
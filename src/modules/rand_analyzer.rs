@@ -1,5 +1,7 @@
 use crate::types::{iv::*, seed::*};
 
+use super::rng_lc::RngLC;
+
 pub struct RandAnalyzer {}
 
 impl RandAnalyzer {
@@ -27,4 +29,147 @@ impl RandAnalyzer {
             ((rand >> 10) & 0x1f) as IV,
         ];
     }
+
+    /*
+        連続した 2 つの乱数値 (rand_1st, rand_2nd) を出すシードを、下位 16bit の総当たりをせずに求める。
+        seed = rand_1st << 16 | low とすると、次のシードの上位が rand_2nd になる条件は
+        (multiplier * low + offset) mod 2^32 が [0, 0xffff] に入ることと同じ
+        (offset = multiplier * (rand_1st << 16) + increment - (rand_2nd << 16))。
+        これを solve_modular_window で解くので、返ってくるのは rand_1st を出すシード (1つ目のシード) の候補。
+    */
+    pub fn seeds_from_rands(&self, rng_lc: &RngLC, rand_1st: Rand, rand_2nd: Rand) -> Vec<Seed> {
+        let seed_high = (rand_1st as Seed) << 16;
+        let offset = rng_lc
+            .multiplier()
+            .wrapping_mul(seed_high)
+            .wrapping_add(rng_lc.increment())
+            .wrapping_sub((rand_2nd as Seed) << 16);
+
+        return solve_modular_window(rng_lc.multiplier(), offset, 0xffff, 0x10000)
+            .into_iter()
+            .map(|low| seed_high | low)
+            .collect();
+    }
+
+    /*
+        個体値の乱数は最上位ビットが使われないため、2 つの個体値グループに対して最上位ビット 0/1 の 4 通りを調べる。
+        返り値は 1 つ目の個体値グループのシード。
+        2 つの個体値の間に消費が挟まる場合は rng_lc.stride(n) を渡す。
+    */
+    pub fn seeds_from_iv_groups(
+        &self,
+        rng_lc: &RngLC,
+        iv_group_1st: IVGroup,
+        iv_group_2nd: IVGroup,
+    ) -> Vec<IV1stSeed> {
+        let iv_rand_1st = self.iv_group_to_rand(iv_group_1st);
+        let iv_rand_2nd = self.iv_group_to_rand(iv_group_2nd);
+        let mut seeds: Vec<IV1stSeed> = Vec::new();
+
+        for (msb_1st, msb_2nd) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            seeds.extend(self.seeds_from_rands(
+                rng_lc,
+                (msb_1st << 15) | iv_rand_1st,
+                (msb_2nd << 15) | iv_rand_2nd,
+            ));
+        }
+
+        return seeds;
+    }
+}
+
+/*
+    0 <= x < count のうち、(multiplier * x + offset) mod 2^32 <= width となる x を全て返す。
+    解は平均で count * (width + 1) / 2^32 個程度しかないため、
+    min_solution で「次の解」を O(log) で求めることを繰り返す。
+*/
+fn solve_modular_window(multiplier: u32, offset: u32, width: u32, count: u32) -> Vec<u32> {
+    let modulus: u128 = 1 << 32;
+    let mut solutions: Vec<u32> = Vec::new();
+    let mut base: u64 = 0;
+
+    while base < count as u64 {
+        // x = base + y として、(multiplier * y) mod 2^32 が [low, high] (折り返しあり) に入る最小の y を求める
+        let shifted = (multiplier as u64 * base + offset as u64) as u32;
+        let low = shifted.wrapping_neg();
+        let high = low.wrapping_add(width);
+
+        let y = if low <= high {
+            min_solution(multiplier as u128, modulus, low as u128, high as u128)
+        } else {
+            let y_1 = min_solution(multiplier as u128, modulus, low as u128, modulus - 1);
+            let y_2 = min_solution(multiplier as u128, modulus, 0, high as u128);
+            match (y_1, y_2) {
+                (Some(y_1), Some(y_2)) => Some(y_1.min(y_2)),
+                (y_1, y_2) => y_1.or(y_2),
+            }
+        };
+
+        let Some(y) = y else {
+            break;
+        };
+
+        let x = base as u128 + y;
+        if count as u128 <= x {
+            break;
+        }
+
+        solutions.push(x as u32);
+        base = x as u64 + 1;
+    }
+
+    return solutions;
+}
+
+/*
+    low <= (a * y) mod m <= high となる最小の y >= 0 を返す (ユークリッドの互除法の要領で再帰)。
+    そのまま a * y が [low, high] に入る y がなければ、a * y - m * k が [low, high] に入る最小の k を
+    (-m * k) mod a についての同じ形の問題として解き、そこから y を戻す。
+*/
+fn min_solution(a: u128, m: u128, low: u128, high: u128) -> Option<u128> {
+    if high < low {
+        return None;
+    }
+
+    if low == 0 {
+        return Some(0);
+    }
+
+    let a = a % m;
+    if a == 0 {
+        return None;
+    }
+
+    let y = low.div_ceil(a);
+    if a * y <= high {
+        return Some(y);
+    }
+
+    let k = min_solution(a - m % a, a, low % a, high % a)?;
+    return Some((low + m * k).div_ceil(a));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_from_rands_test() {
+        let rand_analyzer = RandAnalyzer::new();
+
+        for rng_lc in [RngLC::new(), RngLC::new_arng(), RngLC::new_gc()] {
+            for seed in [0x00000000, 0x12345678, 0xdeadbeef, 0xffffffff] {
+                let rand_1st = rand_analyzer.extract_rand(seed);
+                let rand_2nd = rand_analyzer.extract_rand(rng_lc.next(seed));
+
+                let expected: Vec<Seed> = (0..=0xffff)
+                    .map(|low| (seed & 0xffff0000) | low)
+                    .filter(|s| rand_analyzer.extract_rand(rng_lc.next(*s)) == rand_2nd)
+                    .collect();
+
+                let seeds = rand_analyzer.seeds_from_rands(&rng_lc, rand_1st, rand_2nd);
+                assert_eq!(seeds, expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,63 @@
+use crate::types::seed::*;
+
+/*
+    RngLC (線形合同法) と RngMT (メルセンヌツイスタ) を同じように扱うための共通トレイト。
+    state は「直前に進めた状態」、next_state で 1 回進め、rand で 16bit の乱数値を取り出す。
+    LC なら状態そのものがシード、MT なら状態は未調律 (tempering 前) の出力値になる。
+*/
+pub trait Rng {
+    fn state(&self) -> Seed;
+
+    fn next_state(&mut self) -> Seed;
+
+    fn rand(&self, state: Seed) -> Rand;
+
+    fn next_rand(&mut self) -> Rand {
+        let state = self.next_state();
+        return self.rand(state);
+    }
+
+    fn advance(&mut self, n: u32) {
+        for _ in 0..n {
+            self.next_state();
+        }
+    }
+
+    fn states(&mut self) -> States<'_, Self>
+    where
+        Self: Sized,
+    {
+        return States { rng: self };
+    }
+
+    fn rands(&mut self) -> Rands<'_, Self>
+    where
+        Self: Sized,
+    {
+        return Rands { rng: self };
+    }
+}
+
+pub struct States<'a, R: Rng> {
+    rng: &'a mut R,
+}
+
+impl<R: Rng> Iterator for States<'_, R> {
+    type Item = Seed;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return Some(self.rng.next_state());
+    }
+}
+
+pub struct Rands<'a, R: Rng> {
+    rng: &'a mut R,
+}
+
+impl<R: Rng> Iterator for Rands<'_, R> {
+    type Item = Rand;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return Some(self.rng.next_rand());
+    }
+}
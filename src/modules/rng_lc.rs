@@ -1,5 +1,8 @@
 use crate::types::seed::*;
 
+use super::{rand_analyzer::RandAnalyzer, rng::Rng};
+
+#[derive(Debug, Clone, Copy)]
 pub struct RngLC {
     multiplier: u32,
     increment: u32,
@@ -7,14 +10,49 @@ pub struct RngLC {
 }
 
 impl RngLC {
+    // 通常の乱数 (第4世代のメイン乱数)
     pub fn new() -> Self {
+        Self::from_odd_multiplier(0x41c64e6d, 0x6073)
+    }
+
+    // 第4世代の副乱数 (ARNG)。ふしぎなおくりものやグループシードなどで使われる。
+    pub fn new_arng() -> Self {
+        Self::from_odd_multiplier(0x6c078965, 0x1)
+    }
+
+    // コロシアム/XD の乱数
+    pub fn new_gc() -> Self {
+        Self::from_odd_multiplier(0x343fd, 0x269ec3)
+    }
+
+    /*
+        任意の定数で生成する。
+        逆算 (prev) に逆数が必要なので、乗数は奇数でなければならない。偶数の場合は None。
+    */
+    pub fn with_params(multiplier: u32, increment: u32) -> Option<Self> {
+        if multiplier & 1 == 0 {
+            return None;
+        }
+
+        return Some(Self::from_odd_multiplier(multiplier, increment));
+    }
+
+    fn from_odd_multiplier(multiplier: u32, increment: u32) -> Self {
         Self {
-            multiplier: 0x41c64e6d,
-            increment: 0x6073,
-            inverse_multiplier: 0xeeb9eb65,
+            multiplier,
+            increment,
+            inverse_multiplier: inverse_mod_2_32(multiplier),
         }
     }
 
+    pub fn multiplier(&self) -> u32 {
+        return self.multiplier;
+    }
+
+    pub fn increment(&self) -> u32 {
+        return self.increment;
+    }
+
     pub fn next(&self, seed: Seed) -> Seed {
         let next_seed = (seed as u64) * (self.multiplier as u64) + (self.increment as u64);
         return (next_seed & 0xffffffff) as Seed;
@@ -25,4 +63,198 @@ impl RngLC {
         let prev_seed = (self.inverse_multiplier as u64 * diff as u64) & 0xffffffff;
         return (prev_seed & 0xffffffff) as Seed;
     }
+
+    /*
+        n 回分の消費を 1 回の next で行う RngLC を返す。
+        間に別の消費を挟んだ 2 つの乱数を RandAnalyzer で扱うときなどに使う。
+    */
+    pub fn stride(&self, n: u32) -> Self {
+        let (multiplier, increment) = jump_params(self.multiplier, self.increment, n);
+        return Self::from_odd_multiplier(multiplier, increment);
+    }
+
+    /*
+        seed を n 回進めたシードを返す。
+        next を n 回繰り返す代わりに、一次変換 x -> ax + c を 2^i 回分ずつ合成して O(log n) で求める。
+        周期は 2^32 なので、u64 の n は下位 32bit だけ見れば十分。
+    */
+    pub fn advance(&self, seed: Seed, n: impl Into<u64>) -> Seed {
+        let n = n.into() as u32;
+        let (multiplier, increment) = jump_params(self.multiplier, self.increment, n);
+        return seed.wrapping_mul(multiplier).wrapping_add(increment);
+    }
+
+    /*
+        seed を n 回戻したシードを返す。
+        2^32 - n 回進めるのと同じなので advance に任せる。
+    */
+    pub fn rewind(&self, seed: Seed, n: impl Into<u64>) -> Seed {
+        let n = (n.into() as u32).wrapping_neg();
+        return self.advance(seed, n);
+    }
+
+    /*
+        from から to まで何回進めればよいかを返す。
+        下位ビットから順に、i ビット目が一致しなければ 2^i 回進める、を繰り返す。
+        2^i 回の変換は i ビット目より下を変えず i ビット目だけを反転させるため、32 回で確定する。
+        全周期でない定数の場合は到達できないことがあるので、その場合は None。
+    */
+    pub fn distance(&self, from: Seed, to: Seed) -> Option<u32> {
+        let mut multiplier = self.multiplier;
+        let mut increment = self.increment;
+        let mut seed = from;
+        let mut distance: u32 = 0;
+
+        for i in 0..32 {
+            let bit = 1 << i;
+
+            if (seed ^ to) & bit != 0 {
+                seed = seed.wrapping_mul(multiplier).wrapping_add(increment);
+                distance |= bit;
+            }
+
+            increment = increment.wrapping_mul(multiplier.wrapping_add(1));
+            multiplier = multiplier.wrapping_mul(multiplier);
+        }
+
+        if seed != to {
+            return None;
+        }
+
+        return Some(distance);
+    }
+}
+
+/*
+    状態を持たない RngLC にシードを持たせ、Rng トレイトとして扱えるようにしたもの。
+*/
+pub struct RngLCStream {
+    rng_lc: RngLC,
+    rand_analyzer: RandAnalyzer,
+    seed: Seed,
+}
+
+impl RngLCStream {
+    pub fn new(rng_lc: RngLC, seed: Seed) -> Self {
+        Self {
+            rng_lc,
+            rand_analyzer: RandAnalyzer::new(),
+            seed,
+        }
+    }
+}
+
+impl Rng for RngLCStream {
+    fn state(&self) -> Seed {
+        return self.seed;
+    }
+
+    fn next_state(&mut self) -> Seed {
+        self.seed = self.rng_lc.next(self.seed);
+        return self.seed;
+    }
+
+    fn rand(&self, state: Seed) -> Rand {
+        return self.rand_analyzer.extract_rand(state);
+    }
+
+    fn advance(&mut self, n: u32) {
+        self.seed = self.rng_lc.advance(self.seed, n);
+    }
+}
+
+/*
+    mod 2^32 での逆数をニュートン法で求める。
+    奇数 a に対して x = a は下位 3bit で正しく、1 回ごとに正しいビット数が倍になるので 4 回で 32bit に届く。
+*/
+fn inverse_mod_2_32(multiplier: u32) -> u32 {
+    let mut inverse = multiplier;
+
+    for _ in 0..4 {
+        inverse = inverse.wrapping_mul(2u32.wrapping_sub(multiplier.wrapping_mul(inverse)));
+    }
+
+    return inverse;
+}
+
+/*
+    x -> ax + c を n 回合成した (a', c') を返す。
+    (a, c) を二乗していきながら、n の立っているビットの分だけ掛け合わせる。
+*/
+fn jump_params(multiplier: u32, increment: u32, n: u32) -> (u32, u32) {
+    let mut result_multiplier: u32 = 1;
+    let mut result_increment: u32 = 0;
+    let mut multiplier = multiplier;
+    let mut increment = increment;
+    let mut n = n;
+
+    while n != 0 {
+        if n & 1 != 0 {
+            result_multiplier = result_multiplier.wrapping_mul(multiplier);
+            result_increment = result_increment
+                .wrapping_mul(multiplier)
+                .wrapping_add(increment);
+        }
+
+        increment = increment.wrapping_mul(multiplier.wrapping_add(1));
+        multiplier = multiplier.wrapping_mul(multiplier);
+        n >>= 1;
+    }
+
+    return (result_multiplier, result_increment);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_rewind_distance_test() {
+        let rng_lc = RngLC::new();
+        let seed: Seed = 0x12345678;
+        let mut stepped = seed;
+
+        for _ in 0..50000 {
+            stepped = rng_lc.next(stepped);
+        }
+
+        assert_eq!(rng_lc.advance(seed, 50000u32), stepped);
+        assert_eq!(rng_lc.advance(seed, (1u64 << 32) + 50000), stepped);
+        assert_eq!(rng_lc.rewind(stepped, 50000u32), seed);
+        assert_eq!(rng_lc.distance(seed, stepped), Some(50000));
+        assert_eq!(
+            rng_lc.distance(stepped, seed),
+            Some(50000u32.wrapping_neg())
+        );
+        assert_eq!(rng_lc.distance(seed, seed), Some(0));
+    }
+
+    #[test]
+    fn params_test() {
+        assert_eq!(inverse_mod_2_32(0x41c64e6d), 0xeeb9eb65);
+        assert!(RngLC::with_params(0x41c64e6c, 0x6073).is_none());
+
+        for rng_lc in [RngLC::new_arng(), RngLC::new_gc()] {
+            let seed: Seed = 0xdeadbeef;
+            assert_eq!(rng_lc.prev(rng_lc.next(seed)), seed);
+            assert_eq!(
+                rng_lc.distance(seed, rng_lc.advance(seed, 1234u32)),
+                Some(1234)
+            );
+        }
+
+        assert_eq!(RngLC::new_arng().next(0), 1);
+        assert_eq!(RngLC::new_gc().next(1), 0x343fd + 0x269ec3);
+    }
+
+    #[test]
+    fn stream_test() {
+        let rng_lc = RngLC::new();
+        let mut stream = RngLCStream::new(rng_lc, 0);
+        let states: Vec<Seed> = stream.states().take(3).collect();
+
+        assert_eq!(states, vec![0x00006073, 0xe97e7b6a, 0x52713895]);
+        assert_eq!(stream.state(), 0x52713895);
+        assert_eq!(stream.next_rand(), (rng_lc.next(0x52713895) >> 16) as Rand);
+    }
 }
@@ -1,5 +1,7 @@
 use crate::types::seed::*;
 
+use super::rng::Rng;
+
 const N: usize = 624;
 const M: usize = 397;
 const MATRIX_A: u32 = 0x9908b0df;
@@ -9,6 +11,7 @@ const LOWER_MASK: u32 = 0x7fffffff;
 pub struct RngMT {
     table: [u32; 624],
     index: usize,
+    last_output: Seed, // 直前に出力した値 (調律前)。まだ出力していなければ初期シード
 }
 
 impl RngMT {
@@ -21,7 +24,11 @@ impl RngMT {
             table[i] = ((prev ^ (prev >> 30)).wrapping_mul(0x6c078965)).wrapping_add(i as u32);
         }
 
-        let mut rng = Self { table, index: N };
+        let mut rng = Self {
+            table,
+            index: N,
+            last_output: initial_seed,
+        };
         rng.twist();
         return rng;
     }
@@ -54,10 +61,11 @@ impl RngMT {
 
         let next_seed = self.table[self.index];
         self.index += 1;
+        self.last_output = next_seed;
         return next_seed;
     }
 
-    pub fn get_pid(&mut self, seed: Seed) -> PID {
+    pub fn get_pid(&self, seed: Seed) -> PID {
         let k0 = (seed / 0x800) ^ seed;
         let k1 = ((k0.wrapping_mul(0x80)) & 0x9d2c5680) ^ k0;
         let k2 = ((k1.wrapping_mul(0x8000)) & 0xefc60000) ^ k1;
@@ -65,6 +73,138 @@ impl RngMT {
 
         return pid;
     }
+
+    /*
+        get_pid (調律) の逆変換。
+        右シフトの xor はシフト幅ごとに上位から、左シフト + マスクの xor は下位から順に確定させていく。
+    */
+    pub fn untemper(pid: PID) -> Seed {
+        let k2 = pid ^ (pid >> 18);
+        let k1 = k2 ^ ((k2 << 15) & 0xefc60000);
+
+        let mut k0 = k1;
+        for _ in 0..4 {
+            k0 = k1 ^ ((k0 << 7) & 0x9d2c5680);
+        }
+
+        let mut seed = k0;
+        for _ in 0..2 {
+            seed = k0 ^ (seed >> 11);
+        }
+
+        return seed;
+    }
+
+    /*
+        連続した 624 個の出力 (調律後の値) から内部状態を復元する。
+        復元後の next() は、渡した出力の続きを返す。
+    */
+    pub fn from_outputs(outputs: &[PID; N]) -> Self {
+        let mut table = [0u32; N];
+
+        for (i, output) in outputs.iter().enumerate() {
+            table[i] = Self::untemper(*output);
+        }
+
+        return Self {
+            table,
+            index: N,
+            last_output: table[N - 1],
+        };
+    }
+}
+
+pub const MT_LANES: usize = 8;
+
+/*
+    複数の初期シードについて、最初の count 個の出力 (調律後の値) だけをまとめて求める。
+    最初の twist で i 番目の値を作るのに必要なのは初期化テーブルの i, i + 1, i + M 番目だけなので、
+    count <= N - M ならテーブルを M + count 個まで作れば足り、624 個の初期化と twist 全体を省ける。
+    各シードをレーンとして配列でまとめて処理し、自動ベクトル化させる (wasm は .cargo/config.toml で simd128 を有効にしている)。
+*/
+pub struct RngMTBatch {
+    table: Vec<[u32; MT_LANES]>,
+    pids: Vec<[PID; MT_LANES]>,
+    count: usize,
+}
+
+impl RngMTBatch {
+    pub fn new(count: usize) -> Self {
+        Self {
+            table: vec![[0u32; MT_LANES]; M + count + 1],
+            pids: vec![[0u32; MT_LANES]; count],
+            count,
+        }
+    }
+
+    pub fn pids(&mut self, initial_seeds: &[InitialSeed; MT_LANES]) -> &[[PID; MT_LANES]] {
+        // 最初の twist の範囲を超える場合は通常の MT で求める
+        if N - M < self.count {
+            for (lane, initial_seed) in initial_seeds.iter().enumerate() {
+                let mut mt = RngMT::new(*initial_seed);
+
+                for i in 0..self.count {
+                    let next_seed = mt.next();
+                    self.pids[i][lane] = mt.get_pid(next_seed);
+                }
+            }
+
+            return &self.pids;
+        }
+
+        self.table[0] = *initial_seeds;
+
+        for i in 1..(M + self.count) {
+            let prev = self.table[i - 1];
+            let mut next = [0u32; MT_LANES];
+
+            for lane in 0..MT_LANES {
+                let k = prev[lane] ^ (prev[lane] >> 30);
+                next[lane] = k.wrapping_mul(0x6c078965).wrapping_add(i as u32);
+            }
+
+            self.table[i] = next;
+        }
+
+        for i in 0..self.count {
+            let mut pids = [0u32; MT_LANES];
+
+            for (lane, pid) in pids.iter_mut().enumerate() {
+                let k0 =
+                    (self.table[i][lane] & UPPER_MASK) | (self.table[i + 1][lane] & LOWER_MASK);
+                let mag = (k0 & 1).wrapping_neg() & MATRIX_A;
+                let y = self.table[i + M][lane] ^ (k0 >> 1) ^ mag;
+
+                let k1 = y ^ (y >> 11);
+                let k2 = k1 ^ ((k1 << 7) & 0x9d2c5680);
+                let k3 = k2 ^ ((k2 << 15) & 0xefc60000);
+                *pid = k3 ^ (k3 >> 18);
+            }
+
+            self.pids[i] = pids;
+        }
+
+        return &self.pids;
+    }
+}
+
+/*
+    MT の状態は 624 個のテーブルだが、Rng トレイトとしては直前に出力した値 (調律前) を状態とみなす。
+    まだ一度も出力していない (new 直後) 場合は、RngLC と同じく初期シードを状態とする。
+    乱数値は調律後の値の上位 16bit。
+*/
+impl Rng for RngMT {
+    fn state(&self) -> Seed {
+        return self.last_output;
+    }
+
+    fn next_state(&mut self) -> Seed {
+        return self.next();
+    }
+
+    fn rand(&self, state: Seed) -> Rand {
+        return (self.get_pid(state) >> 16) as Rand;
+    }
 }
 
 #[cfg(test)]
@@ -86,4 +226,52 @@ mod tests {
 
         assert!(arr == answer);
     }
+
+    #[test]
+    fn state_test() {
+        let mut mt = RngMT::new(0x78000489);
+        assert_eq!(mt.state(), 0x78000489);
+
+        let next_seed = mt.next();
+        assert_eq!(mt.state(), next_seed);
+        assert_eq!(mt.next_rand(), (mt.get_pid(mt.state()) >> 16) as Rand);
+    }
+
+    #[test]
+    fn batch_test() {
+        let initial_seeds: [InitialSeed; MT_LANES] =
+            std::array::from_fn(|lane| 0x78000489 + (lane as u32) * 0x10001);
+
+        for count in [11, N - M, N - M + 1] {
+            let mut batch = RngMTBatch::new(count);
+            let pids = batch.pids(&initial_seeds).to_vec();
+
+            for (lane, initial_seed) in initial_seeds.iter().enumerate() {
+                let mut mt = RngMT::new(*initial_seed);
+
+                for lanes in pids.iter() {
+                    let next_seed = mt.next();
+                    assert_eq!(lanes[lane], mt.get_pid(next_seed));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn untemper_test() {
+        let mut mt = RngMT::new(0x78000489);
+        let mut outputs = [0u32; N];
+
+        for output in outputs.iter_mut() {
+            let next_seed = mt.next();
+            assert_eq!(RngMT::untemper(mt.get_pid(next_seed)), next_seed);
+            *output = mt.get_pid(next_seed);
+        }
+
+        let mut restored = RngMT::from_outputs(&outputs);
+        assert_eq!(restored.state(), mt.state());
+        for _ in 0..N {
+            assert_eq!(restored.next(), mt.next());
+        }
+    }
 }
@@ -0,0 +1,286 @@
+use crate::{
+    constants::roaming_routes,
+    types::{characteristic::*, iv::*, method::*, roamer::*, seed::*},
+};
+
+use super::{
+    rng::Rng,
+    rng_lc::{RngLC, RngLCStream},
+    seed_analyzer::SeedAnalyzer,
+};
+
+pub struct RoamerAnalyzer {
+    rng_lc: RngLC,
+    seed_analyzer: SeedAnalyzer,
+}
+
+impl RoamerAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            rng_lc: RngLC::new(),
+            seed_analyzer: SeedAnalyzer::new(),
+        }
+    }
+
+    /*
+        徘徊ポケモンの生成 (Method 1)
+        PID下位 -> PID上位 -> 個体値1 -> 個体値2
+        徘徊を始めた時点で PID と個体値が確定し、以降は出会うまで変わらない。
+    */
+    pub fn generate_roamer_status(
+        &self,
+        origin_seed: Seed,
+        roamer: Roamer,
+        tid: Rand,
+        sid: Rand,
+    ) -> RoamerStatus {
+        let iv_1st_seed = self.rng_lc.advance(origin_seed, 3u32);
+        let mut status = self
+            .seed_analyzer
+            .extract_status(iv_1st_seed, Method::Method1, tid, sid);
+
+        if roamer.truncates_ivs() {
+            status.ivs = truncate_ivs(status.ivs);
+            status.characteristic = Characteristic::from_ivs(&status.ivs, status.pid);
+        }
+
+        return RoamerStatus {
+            roamer,
+            status,
+            origin_seed,
+        };
+    }
+
+    /*
+        initial_seed から 0..=max_advances 消費したそれぞれのフレームで徘徊を始めた場合の個体を列挙する。
+    */
+    pub fn list_roamer_frames(
+        &self,
+        initial_seed: InitialSeed,
+        max_advances: u32,
+        roamer: Roamer,
+        tid: Rand,
+        sid: Rand,
+    ) -> Vec<(u32, RoamerStatus)> {
+        let mut frames: Vec<(u32, RoamerStatus)> = Vec::new();
+        let mut seed = initial_seed;
+
+        for advances in 0..=max_advances {
+            frames.push((
+                advances,
+                self.generate_roamer_status(seed, roamer, tid, sid),
+            ));
+            seed = self.rng_lc.next(seed);
+        }
+
+        return frames;
+    }
+}
+
+/*
+    HGSS の徘徊ポケモンの移動を追いかける。
+    roaming / locations は SeedChecker::create_roamers_location_map と同じく [ライコウ, エンテイ, ラティ] の順。
+    プレイヤーが道路を移動するたびに、徘徊中のポケモンがこの順に移動先を決め直す。
+    犬: 乱数 % 16 がジョウト道路の昇順、ラ: 乱数 % 25 がカントー道路の昇順に対応し、
+    プレイヤーがいる道路を引いた場合は引き直す。
+    遭遇した徘徊ポケモンは、戦闘から逃げる (逃げられる) とそのポケモンだけが同じ決め方で移動し直し、
+    捕まえる (倒す) と徘徊しなくなる。戦闘中に消費した乱数は advance で進める。
+*/
+pub struct RoamerSimulator {
+    rng: RngLCStream,
+    roaming: [bool; 3],
+    locations: [Option<u8>; 3],
+    encountered: Option<usize>,
+}
+
+impl RoamerSimulator {
+    // 初期シードから最初の配置を決める (このときはプレイヤーの位置を避けない)
+    pub fn new(initial_seed: InitialSeed, roaming: [bool; 3]) -> Self {
+        let mut simulator = Self {
+            rng: RngLCStream::new(RngLC::new(), initial_seed),
+            roaming,
+            locations: [None; 3],
+            encountered: None,
+        };
+        simulator.relocate(None);
+        return simulator;
+    }
+
+    pub fn seed(&self) -> Seed {
+        return self.rng.state();
+    }
+
+    pub fn locations(&self) -> [Option<u8>; 3] {
+        return self.locations;
+    }
+
+    // プレイヤーが route に移動したときの、移動後の徘徊ポケモンの位置
+    pub fn move_player(&mut self, route: u8) -> [Option<u8>; 3] {
+        self.relocate(Some(route));
+        return self.locations;
+    }
+
+    /*
+        移動を順に適用し、それぞれの移動後の位置を返す。
+    */
+    pub fn simulate(&mut self, routes: &[u8]) -> Vec<[Option<u8>; 3]> {
+        return routes
+            .iter()
+            .map(|route| self.move_player(*route))
+            .collect();
+    }
+
+    /*
+        index (0: ライコウ, 1: エンテイ, 2: ラティ) の徘徊ポケモンと遭遇する。乱数は消費しない。
+        遭遇した道路を返す。徘徊していなければ None。
+    */
+    pub fn encounter(&mut self, index: usize) -> Option<u8> {
+        if !self.roaming[index] {
+            return None;
+        }
+
+        self.encountered = Some(index);
+        return self.locations[index];
+    }
+
+    // 戦闘などで消費した乱数の数だけ進める
+    pub fn advance(&mut self, advances: u32) {
+        self.rng.advance(advances);
+    }
+
+    /*
+        遭遇中の徘徊ポケモンとの戦闘が逃走で終わったとき、そのポケモンだけが route 以外に移動し直す。
+        移動後の位置を返す。
+    */
+    pub fn flee(&mut self, route: u8) -> [Option<u8>; 3] {
+        if let Some(index) = self.encountered.take() {
+            self.relocate_roamer(index, Some(route));
+        }
+
+        return self.locations;
+    }
+
+    // 遭遇中の徘徊ポケモンを捕まえた (倒した) ので、以降は徘徊しない
+    pub fn capture(&mut self) -> [Option<u8>; 3] {
+        if let Some(index) = self.encountered.take() {
+            self.roaming[index] = false;
+            self.locations[index] = None;
+        }
+
+        return self.locations;
+    }
+
+    fn relocate(&mut self, player_route: Option<u8>) {
+        for i in 0..3 {
+            if self.roaming[i] {
+                self.relocate_roamer(i, player_route);
+            }
+        }
+    }
+
+    fn relocate_roamer(&mut self, index: usize, player_route: Option<u8>) {
+        let route_list: &[u8] = match index {
+            0 | 1 => &roaming_routes::ROAMING_POKEMON_ROUTE_LIST_JOHTO,
+            _ => &roaming_routes::ROAMING_POKEMON_ROUTE_LIST_KANTO,
+        };
+
+        let location = loop {
+            let rand = self.rng.next_rand();
+            let location = route_list[rand as usize % route_list.len()];
+
+            if Some(location) != player_route {
+                break location;
+            }
+        };
+
+        self.locations[index] = Some(location);
+    }
+}
+
+/*
+    個体値1の乱数の下位 8bit だけが残る。
+    HP はそのまま、攻撃は下位 3bit だけになり、それ以外は 0。
+*/
+pub fn truncate_ivs(ivs: IVs) -> IVs {
+    return IVs {
+        hp: ivs.hp,
+        attack: ivs.attack & 0x7,
+        defense: 0,
+        speed: 0,
+        sp_attack: 0,
+        sp_defense: 0,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roamer_status_test() {
+        let roamer_analyzer = RoamerAnalyzer::new();
+        let mut origin_seed: Seed = 0x0c030b3a;
+
+        for _ in 0..20 {
+            // HGSS の徘徊ポケモンは Method 1 そのまま
+            let full = roamer_analyzer.generate_roamer_status(origin_seed, Roamer::Raikou, 0, 0);
+            let truncated =
+                roamer_analyzer.generate_roamer_status(origin_seed, Roamer::Mesprit, 0, 0);
+            let ivs = truncated.status.ivs;
+
+            assert_eq!(full.status.pid, truncated.status.pid);
+            assert_eq!(ivs.hp, full.status.ivs.hp);
+            assert_eq!(ivs.attack, full.status.ivs.attack & 0x7);
+            assert_eq!(
+                [ivs.defense, ivs.speed, ivs.sp_attack, ivs.sp_defense],
+                [0; 4]
+            );
+            assert_eq!(
+                truncated.status.characteristic,
+                Characteristic::from_ivs(&ivs, truncated.status.pid)
+            );
+
+            origin_seed = RngLC::new().next(origin_seed);
+        }
+    }
+
+    #[test]
+    fn roamer_simulator_test() {
+        let initial_seed: InitialSeed = 0x0c030b3a;
+        let mut simulator = RoamerSimulator::new(initial_seed, [true, true, true]);
+
+        // 最初の配置は乱数 1 個ずつ
+        assert_eq!(simulator.seed(), RngLC::new().advance(initial_seed, 3u32));
+
+        let routes = [29, 30, 31, 32, 33, 34, 35, 36];
+        for (route, locations) in routes.iter().zip(simulator.simulate(&routes)) {
+            assert!(locations.iter().all(|location| *location != Some(*route)));
+        }
+
+        let mut dogs_only = RoamerSimulator::new(initial_seed, [true, true, false]);
+        assert_eq!(dogs_only.move_player(29)[2], None);
+    }
+
+    #[test]
+    fn roamer_encounter_test() {
+        let mut simulator = RoamerSimulator::new(0x0c030b3a, [true, true, true]);
+        assert_eq!(simulator.locations(), [Some(32), Some(31), Some(7)]);
+        assert_eq!(simulator.move_player(29), [Some(31), Some(45), Some(17)]);
+
+        // 31番道路でライコウと遭遇し、戦闘で 5 消費してから逃げるとライコウだけが移動する
+        assert_eq!(simulator.encounter(0), Some(31));
+        simulator.advance(5);
+        assert_eq!(simulator.flee(31), [Some(32), Some(45), Some(17)]);
+        assert_eq!(simulator.seed(), 0x3603f286);
+
+        // 遭遇していなければ逃げても移動しない
+        assert_eq!(simulator.flee(32), [Some(32), Some(45), Some(17)]);
+        assert_eq!(simulator.seed(), 0x3603f286);
+
+        // 捕まえたラティは以降移動しない
+        assert_eq!(simulator.encounter(2), Some(17));
+        assert_eq!(simulator.capture(), [Some(32), Some(45), None]);
+        assert_eq!(simulator.encounter(2), None);
+        assert_eq!(simulator.move_player(30)[2], None);
+    }
+}
@@ -2,14 +2,18 @@ use std::collections::HashMap;
 
 use crate::{
     constants::time_sum_map,
-    types::{iv::*, seed::*, status::*},
+    types::{characteristic::*, iv::*, method::*, seed::*, status::*},
 };
 
 use super::{rand_analyzer::RandAnalyzer, rng_lc::RngLC};
 
+// 国際孵化で色違いでなかった場合に PID を引き直す最大回数
+pub const MASUDA_REROLLS: usize = 4;
+
 pub struct SeedAnalyzer {
     rng_analyzer: RandAnalyzer,
     rng_lc: RngLC,
+    arng: RngLC,
 }
 
 impl SeedAnalyzer {
@@ -17,13 +21,20 @@ impl SeedAnalyzer {
         Self {
             rng_analyzer: RandAnalyzer::new(),
             rng_lc: RngLC::new(),
+            arng: RngLC::new_arng(),
         }
     }
 
-    pub fn extract_status(&self, iv_1st_seed: IV1stSeed, tid: Rand, sid: Rand) -> Status {
-        let pid_2nd_seed = self.rng_lc.prev(iv_1st_seed);
+    pub fn extract_status(
+        &self,
+        iv_1st_seed: IV1stSeed,
+        method: Method,
+        tid: Rand,
+        sid: Rand,
+    ) -> Status {
+        let pid_2nd_seed = self.rng_lc.rewind(iv_1st_seed, method.pid_to_iv_advances());
         let pid_1st_seed = self.rng_lc.prev(pid_2nd_seed);
-        let iv_2nd_seed = self.rng_lc.next(iv_1st_seed);
+        let iv_2nd_seed = self.rng_lc.advance(iv_1st_seed, method.iv_to_iv_advances());
 
         let pid_1st_rand = self.rng_analyzer.extract_rand(pid_1st_seed);
         let pid_2nd_rand = self.rng_analyzer.extract_rand(pid_2nd_seed);
@@ -31,6 +42,20 @@ impl SeedAnalyzer {
         let iv_2nd_rand = self.rng_analyzer.extract_rand(iv_2nd_seed);
         let pid = (pid_2nd_rand as PID) << 16 | (pid_1st_rand as PID);
 
+        return self.build_status(pid, iv_1st_rand, iv_2nd_rand, tid, sid);
+    }
+
+    /*
+        PID と個体値の 2 つの乱数から Status を組み立てる。
+    */
+    pub fn build_status(
+        &self,
+        pid: PID,
+        iv_1st_rand: Rand,
+        iv_2nd_rand: Rand,
+        tid: Rand,
+        sid: Rand,
+    ) -> Status {
         let ivs_1st = self.rng_analyzer.rand_to_iv_group(iv_1st_rand);
         let ivs_2nd = self.rng_analyzer.rand_to_iv_group(iv_2nd_rand);
         let nature_num = (pid % 100 % 25) as u8;
@@ -54,9 +79,73 @@ impl SeedAnalyzer {
             ability: ability_num,
             shiny: is_shiny,
             pid: pid,
+            characteristic: Characteristic::from_ivs(&ivs, pid),
         };
     }
 
+    /*
+        個体値1のシードから PID下位のシード (生成に使う最初のシード) を求める。
+    */
+    pub fn pid_1st_seed(&self, iv_1st_seed: IV1stSeed, method: Method) -> PID1stSeed {
+        return self
+            .rng_lc
+            .rewind(iv_1st_seed, method.pid_to_iv_advances() + 1);
+    }
+
+    /*
+        タマゴの PID。outputs は MT の出力をその消費位置から順に返すもの。
+        everstone_nature: HGSS でかわらずのいしを持たせた親 (メタモン) の性格。
+        かわらずのいしがある場合は最初の出力の最上位 bit が 0 なら性格が遺伝し、性格が一致するまで PID を引き直す。
+        遺伝しなかった場合と、かわらずのいしがない場合は次の出力が PID になり、
+        国際孵化なら色違いになるまで最大 MASUDA_REROLLS 回、PID を副乱数 (ARNG) に通して引き直す (MT は消費しない)。
+        返り値は (PID, 消費した MT の出力の数, 性格が遺伝したか)。outputs が足りないか、性格が 25 以上なら None。
+    */
+    pub fn egg_pid(
+        &self,
+        outputs: impl IntoIterator<Item = PID>,
+        international: bool,
+        everstone_nature: Option<u8>,
+        tid: Rand,
+        sid: Rand,
+    ) -> Option<(PID, u16, bool)> {
+        let mut outputs = outputs.into_iter();
+        let mut mt_calls: u16 = 0;
+
+        if let Some(nature) = everstone_nature {
+            if nature >= 25 {
+                return None;
+            }
+
+            mt_calls += 1;
+
+            if outputs.next()? >> 31 == 0 {
+                loop {
+                    let pid = outputs.next()?;
+                    mt_calls += 1;
+
+                    if (pid % 25) as u8 == nature {
+                        return Some((pid, mt_calls, true));
+                    }
+                }
+            }
+        }
+
+        let mut pid = outputs.next()?;
+        mt_calls += 1;
+
+        if international {
+            for _ in 0..MASUDA_REROLLS {
+                if is_shiny(pid, tid, sid) {
+                    break;
+                }
+
+                pid = self.arng.next(pid);
+            }
+        }
+
+        return Some((pid, mt_calls, false));
+    }
+
     /*
       HashMap<year, ((month, day), (hour, minutes, boot_time_sec, second))>
       HashKey(2048): ((1, 1), (20, 03, 10, 45)) -> 2048年 1月1日 20時03分10秒に選択 45秒につづきから選択
@@ -128,8 +217,104 @@ impl SeedAnalyzer {
     }
 }
 
-fn is_shiny(pid: PID, tid: Rand, sid: Rand) -> bool {
+pub fn is_shiny(pid: PID, tid: Rand, sid: Rand) -> bool {
     let tsid_xor = (tid ^ sid) as u32;
     let pid_xor = ((pid >> 16) ^ (pid & 0xffff)) as u32;
     (tsid_xor ^ pid_xor) <= 7
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::{
+        rng::Rng,
+        rng_lc::{RngLC, RngLCStream},
+        rng_mt::RngMT,
+    };
+
+    #[test]
+    fn extract_status_methods_test() {
+        let seed_analyzer = SeedAnalyzer::new();
+        let origin_seed: Seed = 0x0c030b3a;
+
+        for method in [Method::Method1, Method::Method2, Method::Method4] {
+            // 空きの位置だけ乱数を読み捨てながら順に生成する
+            let mut rng = RngLCStream::new(RngLC::new(), origin_seed);
+            let pid_low = rng.next_rand();
+            let pid_1st_seed = rng.state();
+            let pid_high = rng.next_rand();
+            if method == Method::Method2 {
+                rng.next_rand();
+            }
+            let iv_1st_rand = rng.next_rand();
+            let iv_1st_seed = rng.state();
+            if method == Method::Method4 {
+                rng.next_rand();
+            }
+            let iv_2nd_rand = rng.next_rand();
+
+            let expected = seed_analyzer.build_status(
+                (pid_high as PID) << 16 | pid_low as PID,
+                iv_1st_rand,
+                iv_2nd_rand,
+                0,
+                0,
+            );
+            let status = seed_analyzer.extract_status(iv_1st_seed, method, 0, 0);
+
+            assert_eq!(status.pid, expected.pid);
+            assert_eq!(status.ivs, expected.ivs);
+            assert_eq!(
+                seed_analyzer.pid_1st_seed(iv_1st_seed, method),
+                pid_1st_seed
+            );
+        }
+    }
+
+    #[test]
+    fn egg_pid_test() {
+        let seed_analyzer = SeedAnalyzer::new();
+        let outputs: [PID; 6] = [
+            0x12345678, 0x9abcdef0, 0x0f0f1234, 0x11112222, 0x22223333, 0xffff0000,
+        ];
+        let (tid, sid) = (0, 0x0f0f ^ 0x1234);
+        let egg_pid = |international, everstone_nature, tid, sid| {
+            seed_analyzer.egg_pid(outputs, international, everstone_nature, tid, sid)
+        };
+
+        assert_eq!(egg_pid(false, None, tid, sid), Some((0x12345678, 1, false)));
+
+        // 国際孵化の引き直しは ARNG で、MT は消費しない
+        let arng = RngLC::new_arng();
+        let rerolled = arng.advance(0x12345678u32, MASUDA_REROLLS as u32);
+        assert_eq!(egg_pid(true, None, 1, 1), Some((rerolled, 1, false)));
+
+        // 最上位 bit が 0 なので遺伝し、0x11112222 % 25 == 22 まで引き直す
+        assert_eq!(
+            egg_pid(false, Some(22), tid, sid),
+            Some((0x11112222, 4, true))
+        );
+        assert_eq!(egg_pid(false, Some(5), tid, sid), None);
+        assert_eq!(egg_pid(false, Some(25), tid, sid), None);
+        assert_eq!(
+            seed_analyzer.egg_pid([0x80000000, 0x12345678], false, Some(22), tid, sid),
+            Some((0x12345678, 2, false))
+        );
+    }
+
+    #[test]
+    fn masuda_egg_pid_test() {
+        let seed_analyzer = SeedAnalyzer::new();
+        let mut mt = RngMT::new(0x0c030258);
+        let outputs = std::iter::from_fn(|| {
+            let next_seed = mt.next();
+            Some(mt.get_pid(next_seed))
+        });
+
+        // 最初の出力 0x8ef44f97 -> 0x061c3594 -> 0x68d95765 で色違いになる
+        assert_eq!(
+            seed_analyzer.egg_pid(outputs.take(1), true, None, 0, 0x3fbc),
+            Some((0x68d95765, 1, false))
+        );
+    }
+}
@@ -1,8 +1,15 @@
 use std::collections::HashMap;
 
+use itertools::iproduct;
+
 use crate::{constants::roaming_routes, types::seed::*};
 
-use super::{rand_analyzer::RandAnalyzer, rng_lc::RngLC, rng_mt::RngMT};
+use super::{
+    rand_analyzer::RandAnalyzer,
+    rng::Rng,
+    rng_lc::{RngLC, RngLCStream},
+    rng_mt::{MT_LANES, RngMT, RngMTBatch},
+};
 
 pub struct SeedChecker {
     rng_analyzer: RandAnalyzer,
@@ -35,20 +42,15 @@ impl SeedChecker {
         let seeds_to_search: Vec<Seed> = (range_start..=range_end).collect();
 
         for seed in seeds_to_search {
-            let mut _seed = seed;
-
-            for _ in 0..roaming_num {
-                _seed = self.rng_lc.next(_seed);
-            }
+            let mut rng = RngLCStream::new(self.rng_lc, seed);
+            rng.advance(roaming_num as u32);
 
             /*
               とりあえず10回先までの返答内容を表示
               10回で確定できるっぽい？
               この範囲を設定できるようにすることも視野
             */
-            for _ in 0..10 {
-                _seed = self.rng_lc.next(_seed);
-                let rand = self.rng_analyzer.extract_rand(_seed);
+            for rand in rng.rands().take(10) {
                 let response_type = (rand % 3) as u8;
                 call_response_sequence_map
                     .entry(seed)
@@ -153,4 +155,118 @@ impl SeedChecker {
 
         return coin_flip_result_map;
     }
+
+    /*
+        create_coin_flip_result_map の逆引き。
+        観測したコイントスの結果 (表: true) の並びから、それを出す初期シードを探す。
+        返り値は (初期シード, 並びの開始までに消費したMTの回数)。
+    */
+    pub fn search_initial_seeds_from_coin_flips(
+        &self,
+        coin_flips: &[bool],
+        max_advances: u8,
+        max_frame_sum: u16,
+    ) -> Vec<(InitialSeed, u8)> {
+        return self.search_initial_seeds_from_mt(
+            coin_flips.len(),
+            |i, pid| (pid % 2 == 1) == coin_flips[i],
+            max_advances,
+            max_frame_sum,
+        );
+    }
+
+    /*
+        観測したタマゴのPIDなど、MTの出力 (調律後の値) の並びから初期シードを探す。
+    */
+    pub fn search_initial_seeds_from_mt_pids(
+        &self,
+        pids: &[PID],
+        max_advances: u8,
+        max_frame_sum: u16,
+    ) -> Vec<(InitialSeed, u8)> {
+        return self.search_initial_seeds_from_mt(
+            pids.len(),
+            |i, pid| pid == pids[i],
+            max_advances,
+            max_frame_sum,
+        );
+    }
+
+    /*
+        現実に起こりうる初期シード (time_sum: 0..=0xff, hour: 0..=23, frame_sum: 600..=max_frame_sum + 99)
+        だけを総当たりし、advances 回消費した後の出力が observed_len 個すべて matches を満たすものを返す。
+    */
+    fn search_initial_seeds_from_mt(
+        &self,
+        observed_len: usize,
+        matches: impl Fn(usize, PID) -> bool,
+        max_advances: u8,
+        max_frame_sum: u16,
+    ) -> Vec<(InitialSeed, u8)> {
+        let mut results: Vec<(InitialSeed, u8)> = Vec::new();
+
+        if observed_len == 0 {
+            return results;
+        }
+
+        let count = max_advances as usize + observed_len;
+        let mut rng_mt_batch = RngMTBatch::new(count);
+        let frame_sums: Vec<u32> = (600..=(max_frame_sum as u32 + 99)).collect();
+
+        for (time_sum, hour) in iproduct!(0..=0xff, 0..=23) {
+            for chunk in frame_sums.chunks(MT_LANES) {
+                let initial_seeds: [InitialSeed; MT_LANES] = std::array::from_fn(|lane| {
+                    let frame_sum = chunk[lane.min(chunk.len() - 1)];
+                    (time_sum as Seed) << 24 | (hour as Seed) << 16 | frame_sum as Seed
+                });
+                let pids = rng_mt_batch.pids(&initial_seeds);
+
+                for lane in 0..chunk.len() {
+                    for advances in 0..=max_advances {
+                        let is_match = (0..observed_len)
+                            .all(|i| matches(i, pids[advances as usize + i][lane]));
+
+                        if is_match {
+                            results.push((initial_seeds[lane], advances));
+                        }
+                    }
+                }
+            }
+        }
+
+        return results;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_initial_seeds_from_mt_test() {
+        let seed_checker = SeedChecker::new();
+        let (time_sum, hour, frame_sum): (u32, u32, u32) = (0x0c, 3, 650);
+        let initial_seed: InitialSeed = time_sum << 24 | hour << 16 | frame_sum;
+
+        // 初期シードから 2 回消費した位置からの出力を観測したことにする
+        let mut mt = RngMT::new(initial_seed);
+        let pids: Vec<PID> = (0..22)
+            .map(|_| {
+                let next_seed = mt.next();
+                mt.get_pid(next_seed)
+            })
+            .skip(2)
+            .collect();
+        let coin_flips: Vec<bool> = pids.iter().map(|pid| pid % 2 == 1).collect();
+
+        let results = seed_checker.search_initial_seeds_from_coin_flips(&coin_flips, 3, 600);
+        assert!(results.contains(&(initial_seed, 2)));
+
+        let results = seed_checker.search_initial_seeds_from_mt_pids(&pids[0..3], 3, 600);
+        assert_eq!(results, vec![(initial_seed, 2)]);
+
+        // frame_sum が範囲外なら見つからない
+        let results = seed_checker.search_initial_seeds_from_mt_pids(&pids[0..3], 3, 500);
+        assert!(results.is_empty());
+    }
 }
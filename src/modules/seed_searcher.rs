@@ -1,12 +1,36 @@
 use itertools::iproduct;
 use serde::Deserialize;
 
+use super::egg_analyzer::{EggAnalyzer, egg_species};
 use super::rand_analyzer::RandAnalyzer;
 use super::rng_lc::RngLC;
-use super::rng_mt::RngMT;
-use super::seed_analyzer::SeedAnalyzer;
+use super::rng_mt::{MT_LANES, RngMT, RngMTBatch};
+use super::roamer_analyzer::RoamerAnalyzer;
+use super::seed_analyzer::{SeedAnalyzer, is_shiny};
+use super::wild_analyzer::WildAnalyzer;
+use crate::constants::species::gender_ratio;
+use crate::types::characteristic::*;
+use crate::types::egg::*;
+use crate::types::gender::*;
+use crate::types::hidden_power::*;
 use crate::types::iv::*;
+use crate::types::lead::*;
+use crate::types::method::*;
+use crate::types::pid_traits::*;
+use crate::types::roamer::*;
 use crate::types::seed::*;
+use crate::types::status::*;
+use crate::types::version::*;
+use crate::types::wild::*;
+
+// かわらずのいしがある場合にまとめて求める MT の出力の数 (RngMTBatch の高速化が効く範囲)
+const EVERSTONE_BATCH_CALLS: usize = 200;
+
+// かわらずのいしで性格が一致するまで引き直す MT の出力の上限 (一致しない確率は (24/25)^1000 程度)
+const EVERSTONE_MAX_CALLS: usize = 1000;
+
+// 個体値が切り詰められる徘徊ポケモンの探索で返す結果の上限 (条件が緩いと候補が数百万件になる)
+pub const MAX_ROAMER_RESULTS: usize = 1000;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SearchParams {
@@ -18,33 +42,131 @@ pub struct SearchParams {
     pub sid: Rand,
     pub max_advances: u16,
     pub max_frame_sum: u16, // 最低値は600で固定
+    #[serde(default = "default_methods")]
+    pub methods: Vec<Method>, // search_seeds_from_status で探索する乱数の並び (複数指定可。空なら Method1)
+    #[serde(default)]
+    pub lead: Lead,
+    #[serde(default)]
+    pub stationary: bool, // 固定シンボル (伝説など) なら野生と同じ性格の決め方 (Method J/K) で探す
+    #[serde(default)]
+    pub encounter_slots: Vec<u8>, // 野生で許容する出現スロットの番号 (空なら全て)
+    #[serde(default)]
+    pub held_item: Option<HeldItem>, // 野生で必要な持ち物の判定結果 (None なら問わない)
+    #[serde(default)]
+    pub cute_charmed: bool, // 野生でメロメロボディが発動するフレームだけにする
+    #[serde(default)]
+    pub international: bool, // 国際孵化 (親の言語が異なる) ならタマゴの PID を引き直す
+    #[serde(default)]
+    pub everstone_nature: Option<u8>, // HGSS でかわらずのいしを持たせた親 (メタモン) の性格
+    #[serde(default)]
+    pub species: Option<u16>, // 種族 (全国図鑑番号)。タマゴは生まれる種族 (ニドラン♀/イルミーゼは性別で分かれる)
+    #[serde(default)]
+    pub gender: Option<Gender>, // 性別 (None なら問わない。種族が分からない結果は除外される)
+    #[serde(default)]
+    pub hidden_power_types: Vec<HiddenPowerType>, // 許容するめざパのタイプ (空なら全て)
+    #[serde(default)]
+    pub min_hidden_power: u8, // めざパの最低威力 (30 以下なら問わない)
+    #[serde(default)]
+    pub characteristic: Option<Characteristic>, // 個性 (None なら問わない)
 }
 
+#[derive(Default)]
 pub struct ReturnParams {
     pub initial_seed: Option<InitialSeed>,
     pub ivs: Option<IVs>,
     pub pid: Option<PID>,
     pub nature: Option<u8>,
-    pub gender: Option<u8>,
+    pub gender: Option<Gender>,   // 種族が分かる場合だけ決まる
+    pub gender_value: Option<u8>, // PID の下位 8bit
     pub ability: Option<u8>,
     pub advances: Option<u16>,
     pub time_sum: Option<u16>,
     pub hour: Option<u16>,
     pub frame_sum: Option<u16>,
+    pub method: Option<Method>,
+    pub slot_value: Option<u8>, // 出現スロットを決める値 (0 ~ 99)
+    pub encounter_slot: Option<u8>,
+    pub species: Option<u16>,
+    pub level: Option<u8>,
+    pub synchronized: Option<bool>,
+    pub cute_charmed: Option<bool>,
+    pub held_item: Option<HeldItem>,
+    pub mt_calls: Option<u16>, // タマゴの PID に消費した MT の出力の数
+    pub nature_inherited: Option<bool>, // かわらずのいしで性格が遺伝したか
+    pub iv_sources: Option<[IVSource; 6]>, // タマゴの個体値の出どころ (hp, attack, defense, speed, sp_attack, sp_defense の順)
+    pub hidden_power: Option<HiddenPower>,
+    pub characteristic: Option<Characteristic>,
+    pub pid_traits: Option<PidTraits>,
+}
+
+fn default_methods() -> Vec<Method> {
+    return vec![Method::Method1];
+}
+
+impl SearchParams {
+    // search_seeds_from_status で探索する乱数の並び
+    pub fn methods(&self) -> &[Method] {
+        if self.methods.is_empty() {
+            return &[Method::Method1];
+        }
+
+        return &self.methods;
+    }
+
+    // 個性の条件を満たすか
+    pub fn check_characteristic(&self, status: &Status) -> bool {
+        return self
+            .characteristic
+            .is_none_or(|characteristic| characteristic == status.characteristic);
+    }
+
+    // PID が分からないタマゴの個体値で、個性の条件を満たしうるか
+    pub fn check_egg_characteristic(&self, ivs: &IVs) -> bool {
+        return self.characteristic.is_none_or(|characteristic| {
+            let iv_ranges = IVRanges {
+                hp: ivs.hp..=ivs.hp,
+                attack: ivs.attack..=ivs.attack,
+                defense: ivs.defense..=ivs.defense,
+                speed: ivs.speed..=ivs.speed,
+                sp_attack: ivs.sp_attack..=ivs.sp_attack,
+                sp_defense: ivs.sp_defense..=ivs.sp_defense,
+            };
+            characteristic.narrow_iv_ranges(&iv_ranges).is_some()
+        });
+    }
+
+    // めざパのタイプと威力の条件を満たすか
+    pub fn check_hidden_power(&self, ivs: &IVs) -> bool {
+        if self.hidden_power_types.is_empty() && self.min_hidden_power <= 30 {
+            return true;
+        }
+
+        let hidden_power = ivs.hidden_power();
+        let check_type = self.hidden_power_types.is_empty()
+            || self.hidden_power_types.contains(&hidden_power.hp_type);
+
+        return check_type && self.min_hidden_power <= hidden_power.power;
+    }
 }
 
 pub struct SeedSearcher {
+    egg_analyzer: EggAnalyzer,
     rng_analyzer: RandAnalyzer,
     rng_lc: RngLC,
     seed_analyzer: SeedAnalyzer,
+    wild_analyzer: WildAnalyzer,
+    roamer_analyzer: RoamerAnalyzer,
 }
 
 impl SeedSearcher {
     pub fn new() -> Self {
         Self {
+            egg_analyzer: EggAnalyzer::new(),
             rng_analyzer: RandAnalyzer::new(),
             rng_lc: RngLC::new(),
             seed_analyzer: SeedAnalyzer::new(),
+            wild_analyzer: WildAnalyzer::new(),
+            roamer_analyzer: RoamerAnalyzer::new(),
         }
     }
 
@@ -52,19 +174,341 @@ impl SeedSearcher {
         個体値/性格/特性/めざパ-タイプ/めざパ-威力/色違い(TID/SID)
         これらのパラメータから目的のシードを探索する。
         この処理で求められるシードは個体値の一つ目のシードであり、初期シードではないので注意。
+        固定シンボル (params.stationary) は先頭の特性によらず、性格 -> PID (性格が一致するまで繰り返し) の順に生成される。
+        その決め方は version で決まり (DPPt は Method J, HGSS は Method K)、乱数の並びは Method1 だけになる。
     */
-    pub fn search_seeds_from_status(&self, params: SearchParams) -> Vec<ReturnParams> {
+    pub fn search_seeds_from_status(
+        &self,
+        params: SearchParams,
+        version: GameVersion,
+    ) -> Vec<ReturnParams> {
+        if params.stationary {
+            // 固定シンボルには Method2/4 の並びがないので、それ以外が指定されていれば該当なし
+            if params
+                .methods()
+                .iter()
+                .any(|method| *method != Method::Method1)
+            {
+                return Vec::new();
+            }
+
+            let wild_params = WildParams {
+                method: version.wild_method(),
+                encounter_type: EncounterType::Static,
+                area: None,
+            };
+            return self.search_seeds_from_wild_status(params, wild_params);
+        }
+
         let mut result: Vec<ReturnParams> = Vec::new();
 
-        let iv_range_group_1 = [
+        for method in params.methods().iter() {
+            let iv_gap = method.iv_to_iv_advances();
+
+            self.for_each_iv_1st_seed(&params, iv_gap, |iv_1st_seed| {
+                let status =
+                    self.seed_analyzer
+                        .extract_status(iv_1st_seed, *method, params.tid, params.sid);
+                let check_nature = params.nature == -1 || params.nature == status.nature as i16;
+                let check_ability = params.ability == -1 || params.ability == status.ability as i16;
+                let check_shiny = !params.shiny || status.shiny;
+                let gender = resolve_gender(params.species, status.pid);
+                let check_gender = params.gender.is_none_or(|g| gender == Some(g));
+                let check_characteristic = params.check_characteristic(&status);
+
+                if check_nature
+                    && check_ability
+                    && check_shiny
+                    && check_gender
+                    && check_characteristic
+                {
+                    let pid_1st_seed = self.seed_analyzer.pid_1st_seed(iv_1st_seed, *method);
+
+                    let initial_seed_data = self.search_initial_seed(
+                        pid_1st_seed,
+                        params.max_advances,
+                        params.max_frame_sum,
+                    );
+
+                    if let Some(init_seed_data) = initial_seed_data {
+                        result.push(ReturnParams {
+                            initial_seed: Some(init_seed_data.0),
+                            ivs: Some(status.ivs),
+                            hidden_power: Some(status.ivs.hidden_power()),
+                            characteristic: Some(status.characteristic),
+                            pid: Some(status.pid),
+                            pid_traits: Some(PidTraits::decode(status.pid, params.species)),
+                            nature: Some(status.nature),
+                            gender,
+                            gender_value: Some(status.gender),
+                            ability: Some(status.ability),
+                            advances: Some(init_seed_data.1),
+                            time_sum: Some(init_seed_data.2),
+                            hour: Some(init_seed_data.3),
+                            frame_sum: Some(init_seed_data.4),
+                            method: Some(*method),
+                            ..Default::default()
+                        });
+                    }
+                }
+            });
+        }
+
+        return result;
+    }
+
+    /*
+        野生 (Method J/K) の個体値/性格/特性/色違いから目的のシードを探索する。
+        個体値のシードから PID、性格の乱数、出現スロットの乱数と遡り、生成が始まるシードを求める。
+    */
+    pub fn search_seeds_from_wild_status(
+        &self,
+        params: SearchParams,
+        wild_params: WildParams,
+    ) -> Vec<ReturnParams> {
+        let mut result: Vec<ReturnParams> = Vec::new();
+
+        self.for_each_iv_1st_seed(&params, 1, |iv_1st_seed| {
+            for wild_status in self.wild_analyzer.search_wild_origins(
+                iv_1st_seed,
+                &wild_params,
+                params.lead,
+                params.tid,
+                params.sid,
+            ) {
+                let status = wild_status.status;
+                let check_nature = params.nature == -1 || params.nature == status.nature as i16;
+                let check_ability = params.ability == -1 || params.ability == status.ability as i16;
+                let check_shiny = !params.shiny || status.shiny;
+                let check_slot = params.encounter_slots.is_empty()
+                    || wild_status
+                        .encounter_slot
+                        .is_some_and(|slot| params.encounter_slots.contains(&slot));
+
+                let check_held_item = params
+                    .held_item
+                    .is_none_or(|held_item| held_item == wild_status.held_item);
+                let check_cute_charm = !params.cute_charmed || wild_status.cute_charmed;
+
+                // 出現データがあればスロットの種族、なければ指定された種族で性別を決める
+                let gender = resolve_gender(wild_status.species.or(params.species), status.pid);
+                let check_gender = params.gender.is_none_or(|g| gender == Some(g));
+                let check_characteristic = params.check_characteristic(&status);
+
+                if !(check_nature
+                    && check_ability
+                    && check_shiny
+                    && check_slot
+                    && check_held_item
+                    && check_cute_charm
+                    && check_gender
+                    && check_characteristic)
+                {
+                    continue;
+                }
+
+                let first_seed = self.rng_lc.next(wild_status.origin_seed);
+                let initial_seed_data =
+                    self.search_initial_seed(first_seed, params.max_advances, params.max_frame_sum);
+
+                if let Some(init_seed_data) = initial_seed_data {
+                    result.push(ReturnParams {
+                        initial_seed: Some(init_seed_data.0),
+                        ivs: Some(status.ivs),
+                        hidden_power: Some(status.ivs.hidden_power()),
+                        characteristic: Some(status.characteristic),
+                        pid: Some(status.pid),
+                        pid_traits: Some(PidTraits::decode(
+                            status.pid,
+                            wild_status.species.or(params.species),
+                        )),
+                        nature: Some(status.nature),
+                        gender,
+                        gender_value: Some(status.gender),
+                        ability: Some(status.ability),
+                        advances: Some(init_seed_data.1),
+                        time_sum: Some(init_seed_data.2),
+                        hour: Some(init_seed_data.3),
+                        frame_sum: Some(init_seed_data.4),
+                        slot_value: wild_status.slot_value,
+                        encounter_slot: wild_status.encounter_slot,
+                        species: wild_status.species,
+                        level: wild_status.level,
+                        synchronized: Some(wild_status.synchronized),
+                        cute_charmed: Some(wild_status.cute_charmed),
+                        held_item: Some(wild_status.held_item),
+                        ..Default::default()
+                    });
+                }
+            }
+        });
+
+        return result;
+    }
+
+    /*
+        徘徊ポケモンの個体値/性格/特性/色違いから、徘徊を始めるシードを探索する。
+        個体値が切り詰められない徘徊ポケモンは通常の Method 1 と同じ。
+        切り詰められる場合は HP と攻撃の下位 3bit しか分からないため、
+        個体値1の乱数の上位 8bit と下位 16bit を総当たりし、結果は MAX_ROAMER_RESULTS 件で打ち切る。
+    */
+    pub fn search_seeds_from_roamer_status(
+        &self,
+        params: SearchParams,
+        roamer: Roamer,
+    ) -> Vec<ReturnParams> {
+        if !roamer.truncates_ivs() {
+            let params = SearchParams {
+                methods: vec![Method::Method1],
+                lead: Lead::None,
+                stationary: false,
+                species: Some(roamer.species()),
+                ..params
+            };
+
+            // 個体値が切り詰められないのは HGSS の徘徊ポケモン (先頭の特性は関係しない)
+            return self
+                .search_seeds_from_status(params, GameVersion::HeartGoldSoulSilver)
+                .into_iter()
+                .map(|result| ReturnParams {
+                    species: Some(roamer.species()),
+                    level: Some(roamer.level()),
+                    ..result
+                })
+                .collect();
+        }
+
+        let mut result: Vec<ReturnParams> = Vec::new();
+
+        // 切り詰め後は HP と攻撃 (0 ~ 7) 以外は 0 なので、0 を含まない範囲が指定されていれば該当なし
+        let zero_ivs_contains_range = [
+            &params.iv_ranges.defense,
+            &params.iv_ranges.speed,
+            &params.iv_ranges.sp_attack,
+            &params.iv_ranges.sp_defense,
+        ]
+        .iter()
+        .all(|range| range.contains(&0));
+
+        if !zero_ivs_contains_range {
+            return result;
+        }
+
+        'search: for (hp, attack) in iproduct!(
             params.iv_ranges.hp.clone(),
-            params.iv_ranges.attack.clone(),
-            params.iv_ranges.defense.clone(),
+            params.iv_ranges.attack.clone().filter(|attack| *attack < 8)
+        ) {
+            for (iv_rand_upper, iv_rand_low) in iproduct!(0..=0xff, 0..=0xffff) {
+                if result.len() >= MAX_ROAMER_RESULTS {
+                    break 'search;
+                }
+
+                let iv_rand_high = iv_rand_upper << 8 | (attack as Rand) << 5 | hp as Rand;
+                let iv_1st_seed = self.rng_analyzer.rands_to_seed(iv_rand_high, iv_rand_low);
+                let pid_2nd_seed = self.rng_lc.prev(iv_1st_seed);
+                let pid_1st_seed = self.rng_lc.prev(pid_2nd_seed);
+
+                // 総当たりの数が多いので、PID だけで決まる性格・特性・色違いを先に確かめる
+                let pid = (self.rng_analyzer.extract_rand(pid_2nd_seed) as PID) << 16
+                    | self.rng_analyzer.extract_rand(pid_1st_seed) as PID;
+                if (params.nature != -1 && params.nature != (pid % 25) as i16)
+                    || (params.ability != -1 && params.ability != (pid & 1) as i16)
+                    || (params.shiny && !is_shiny(pid, params.tid, params.sid))
+                {
+                    continue;
+                }
+
+                let origin_seed = self.rng_lc.prev(pid_1st_seed);
+                let roamer_status = self.roamer_analyzer.generate_roamer_status(
+                    origin_seed,
+                    roamer,
+                    params.tid,
+                    params.sid,
+                );
+                let status = roamer_status.status;
+
+                let check_nature = params.nature == -1 || params.nature == status.nature as i16;
+                let check_ability = params.ability == -1 || params.ability == status.ability as i16;
+                let check_shiny = !params.shiny || status.shiny;
+                let gender = resolve_gender(Some(roamer.species()), status.pid);
+                let check_gender = params.gender.is_none_or(|g| gender == Some(g));
+
+                let check_hidden_power = params.check_hidden_power(&status.ivs);
+                let check_characteristic = params.check_characteristic(&status);
+
+                if !(check_nature
+                    && check_ability
+                    && check_shiny
+                    && check_gender
+                    && check_hidden_power
+                    && check_characteristic)
+                {
+                    continue;
+                }
+
+                let initial_seed_data = self.search_initial_seed(
+                    pid_1st_seed,
+                    params.max_advances,
+                    params.max_frame_sum,
+                );
+
+                if let Some(init_seed_data) = initial_seed_data {
+                    result.push(ReturnParams {
+                        initial_seed: Some(init_seed_data.0),
+                        ivs: Some(status.ivs),
+                        hidden_power: Some(status.ivs.hidden_power()),
+                        characteristic: Some(status.characteristic),
+                        pid: Some(status.pid),
+                        pid_traits: Some(PidTraits::decode(status.pid, Some(roamer.species()))),
+                        nature: Some(status.nature),
+                        gender,
+                        gender_value: Some(status.gender),
+                        ability: Some(status.ability),
+                        advances: Some(init_seed_data.1),
+                        time_sum: Some(init_seed_data.2),
+                        hour: Some(init_seed_data.3),
+                        frame_sum: Some(init_seed_data.4),
+                        method: Some(Method::Method1),
+                        species: Some(roamer.species()),
+                        level: Some(roamer.level()),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        return result;
+    }
+
+    /*
+        iv_ranges に入る個体値を出す、個体値の 1 つ目のシードを全て列挙して f に渡す。
+        iv_gap は個体値1のシードから個体値2のシードまでの消費数。
+        組み合わせの少ない方のグループを固定し、もう片方のグループは
+        組み合わせが少なければ RandAnalyzer で直接、多ければ下位 16bit の総当たりで求める。
+    */
+    fn for_each_iv_1st_seed(
+        &self,
+        params: &SearchParams,
+        iv_gap: u32,
+        mut f: impl FnMut(IV1stSeed),
+    ) {
+        // 個性が分かっていれば個体値の範囲を先に絞る
+        let iv_ranges = match params.characteristic {
+            Some(characteristic) => match characteristic.narrow_iv_ranges(&params.iv_ranges) {
+                Some(iv_ranges) => iv_ranges,
+                None => return,
+            },
+            None => params.iv_ranges.clone(),
+        };
+        let iv_range_group_1 = [
+            iv_ranges.hp.clone(),
+            iv_ranges.attack.clone(),
+            iv_ranges.defense.clone(),
         ];
         let iv_range_group_2 = [
-            params.iv_ranges.speed.clone(),
-            params.iv_ranges.sp_attack.clone(),
-            params.iv_ranges.sp_defense.clone(),
+            iv_ranges.speed.clone(),
+            iv_ranges.sp_attack.clone(),
+            iv_ranges.sp_defense.clone(),
         ];
 
         let calc_complexity = |iv_ranges: &[IVRange; 3]| {
@@ -82,87 +526,121 @@ impl SeedSearcher {
             (iv_range_group_2, iv_range_group_1, false)
         };
 
+        // 大きい方のグループの組み合わせがこれ以下なら、下位 16bit を総当たりせず直接シードを求める
+        let larger_group_count: usize = larger_group
+            .iter()
+            .map(|range| range.clone().count())
+            .product();
+        let use_closed_form = larger_group_count <= 0x1000;
+
         for (iv_1, iv_2, iv_3) in iproduct!(
             smaller_group[0].clone(),
             smaller_group[1].clone(),
             smaller_group[2].clone()
         ) {
             let iv_group: IVGroup = [iv_1, iv_2, iv_3];
-            let iv_rand_high_msb_0 = self.rng_analyzer.iv_group_to_rand(iv_group);
-            let iv_rand_high_msb_1 = (1 << 15) | iv_rand_high_msb_0;
-            let iv_rand_high_group = [iv_rand_high_msb_0, iv_rand_high_msb_1];
 
-            for (iv_rand_high, iv_rand_low) in iproduct!(iv_rand_high_group, 0..=0xffff) {
-                let mut ivs: [u8; 6] = [0; 6];
+            let iv_1st_seeds = if use_closed_form {
+                self.iv_1st_seeds_from_iv_groups(params, iv_group, &larger_group, forward, iv_gap)
+            } else {
+                self.iv_1st_seeds_by_brute_force(params, iv_group, &larger_group, forward, iv_gap)
+            };
 
-                let (iv_1st_seed, iv_2nd_iv_group) = if forward {
-                    let iv_1st_seed = self.rng_analyzer.rands_to_seed(iv_rand_high, iv_rand_low);
-                    let iv_2nd_seed = self.rng_lc.next(iv_1st_seed);
-                    let iv_2nd_rand = self.rng_analyzer.extract_rand(iv_2nd_seed);
-                    let iv_2nd_iv_group = self.rng_analyzer.rand_to_iv_group(iv_2nd_rand);
-                    ivs[0..3].copy_from_slice(&iv_group);
-                    ivs[3..6].copy_from_slice(&iv_2nd_iv_group);
-                    (iv_1st_seed, iv_2nd_iv_group)
-                } else {
-                    let iv_2nd_seed = self.rng_analyzer.rands_to_seed(iv_rand_high, iv_rand_low);
-                    let iv_1st_seed = self.rng_lc.prev(iv_2nd_seed);
-                    let iv_1st_rand = self.rng_analyzer.extract_rand(iv_1st_seed);
-                    let iv_2nd_iv_group = self.rng_analyzer.rand_to_iv_group(iv_1st_rand);
-                    ivs[0..3].copy_from_slice(&iv_2nd_iv_group);
-                    ivs[3..6].copy_from_slice(&iv_group);
-                    (iv_1st_seed, iv_2nd_iv_group)
-                };
+            for iv_1st_seed in iv_1st_seeds {
+                f(iv_1st_seed);
+            }
+        }
+    }
 
-                let ivs_contains_range = larger_group
-                    .iter()
-                    .zip(iv_2nd_iv_group.iter())
-                    .all(|(range, value)| range.contains(value));
+    /*
+        小さい方のグループの個体値 iv_group を固定し、大きい方のグループの範囲に入る個体値の 1 つ目のシードを返す。
+        大きい方のグループの組み合わせごとに RandAnalyzer::seeds_from_iv_groups で直接求める。
+    */
+    fn iv_1st_seeds_from_iv_groups(
+        &self,
+        params: &SearchParams,
+        iv_group: IVGroup,
+        larger_group: &IVRangeGroup,
+        forward: bool,
+        iv_gap: u32,
+    ) -> Vec<IV1stSeed> {
+        let mut iv_1st_seeds: Vec<IV1stSeed> = Vec::new();
 
-                let status = self
-                    .seed_analyzer
-                    .extract_status(iv_1st_seed, params.tid, params.sid);
-                let check_nature = params.nature == -1 || params.nature == status.nature as i16;
-                let check_ability = params.ability == -1 || params.ability == status.ability as i16;
-                let check_shiny = !params.shiny || status.shiny;
+        for (iv_1, iv_2, iv_3) in iproduct!(
+            larger_group[0].clone(),
+            larger_group[1].clone(),
+            larger_group[2].clone()
+        ) {
+            let larger_iv_group: IVGroup = [iv_1, iv_2, iv_3];
+            let (iv_group_1st, iv_group_2nd) = if forward {
+                (iv_group, larger_iv_group)
+            } else {
+                (larger_iv_group, iv_group)
+            };
 
-                if ivs_contains_range && check_nature && check_ability && check_shiny {
-                    let pid_2nd_seed = self.rng_lc.prev(iv_1st_seed);
-                    let pid_1st_seed = self.rng_lc.prev(pid_2nd_seed);
+            // めざパの条件は個体値だけで決まるので、シードを求める前に絞り込む
+            if !params.check_hidden_power(&IVs::from_groups(iv_group_1st, iv_group_2nd)) {
+                continue;
+            }
 
-                    let initial_seed_data = self.search_initial_seed(
-                        pid_1st_seed,
-                        params.max_advances,
-                        params.max_frame_sum,
-                    );
+            iv_1st_seeds.extend(self.rng_analyzer.seeds_from_iv_groups(
+                &self.rng_lc.stride(iv_gap),
+                iv_group_1st,
+                iv_group_2nd,
+            ));
+        }
 
-                    let ivs = IVs {
-                        hp: ivs[0],
-                        attack: ivs[1],
-                        defense: ivs[2],
-                        speed: ivs[3],
-                        sp_attack: ivs[4],
-                        sp_defense: ivs[5],
-                    };
+        return iv_1st_seeds;
+    }
 
-                    if let Some(init_seed_data) = initial_seed_data {
-                        result.push(ReturnParams {
-                            initial_seed: Some(init_seed_data.0),
-                            ivs: Some(ivs),
-                            pid: Some(status.pid),
-                            nature: Some(status.nature),
-                            gender: Some(status.gender),
-                            ability: Some(status.ability),
-                            advances: Some(init_seed_data.1),
-                            time_sum: Some(init_seed_data.2),
-                            hour: Some(init_seed_data.3),
-                            frame_sum: Some(init_seed_data.4),
-                        });
-                    }
-                }
+    /*
+        iv_1st_seeds_from_iv_groups と同じ結果を、下位 16bit を総当たりして求める。
+        大きい方のグループの範囲が広い場合はこちらの方が速い。
+    */
+    fn iv_1st_seeds_by_brute_force(
+        &self,
+        params: &SearchParams,
+        iv_group: IVGroup,
+        larger_group: &IVRangeGroup,
+        forward: bool,
+        iv_gap: u32,
+    ) -> Vec<IV1stSeed> {
+        let mut iv_1st_seeds: Vec<IV1stSeed> = Vec::new();
+
+        let iv_rand_high_msb_0 = self.rng_analyzer.iv_group_to_rand(iv_group);
+        let iv_rand_high_msb_1 = (1 << 15) | iv_rand_high_msb_0;
+        let iv_rand_high_group = [iv_rand_high_msb_0, iv_rand_high_msb_1];
+
+        for (iv_rand_high, iv_rand_low) in iproduct!(iv_rand_high_group, 0..=0xffff) {
+            let (iv_1st_seed, iv_2nd_iv_group) = if forward {
+                let iv_1st_seed = self.rng_analyzer.rands_to_seed(iv_rand_high, iv_rand_low);
+                let iv_2nd_seed = self.rng_lc.advance(iv_1st_seed, iv_gap);
+                let iv_2nd_rand = self.rng_analyzer.extract_rand(iv_2nd_seed);
+                (iv_1st_seed, self.rng_analyzer.rand_to_iv_group(iv_2nd_rand))
+            } else {
+                let iv_2nd_seed = self.rng_analyzer.rands_to_seed(iv_rand_high, iv_rand_low);
+                let iv_1st_seed = self.rng_lc.rewind(iv_2nd_seed, iv_gap);
+                let iv_1st_rand = self.rng_analyzer.extract_rand(iv_1st_seed);
+                (iv_1st_seed, self.rng_analyzer.rand_to_iv_group(iv_1st_rand))
+            };
+
+            let ivs_contains_range = larger_group
+                .iter()
+                .zip(iv_2nd_iv_group.iter())
+                .all(|(range, value)| range.contains(value));
+
+            let ivs = if forward {
+                IVs::from_groups(iv_group, iv_2nd_iv_group)
+            } else {
+                IVs::from_groups(iv_2nd_iv_group, iv_group)
+            };
+
+            if ivs_contains_range && params.check_hidden_power(&ivs) {
+                iv_1st_seeds.push(iv_1st_seed);
             }
         }
 
-        return result;
+        return iv_1st_seeds;
     }
 
     /*
@@ -176,43 +654,101 @@ impl SeedSearcher {
     pub fn search_seeds_from_egg_pid(&self, params: SearchParams) -> Vec<ReturnParams> {
         let mut result: Vec<ReturnParams> = Vec::new();
 
+        // 性格は 0 ~ 24 なので、それ以外のかわらずのいしの性格は一致するまで引き直しが終わらない
+        if params.everstone_nature.is_some_and(|nature| nature >= 25) {
+            return result;
+        }
+
+        // 性格の引き直しは平均 25 回なので、ほとんどの場合に足りる分だけまとめて求め、足りなければ通常の MT で求める
+        let max_calls = match params.everstone_nature {
+            Some(_) => EVERSTONE_BATCH_CALLS,
+            None => 1,
+        };
+        let mut rng_mt_batch = RngMTBatch::new(11 + max_calls);
+
+        let candidates: Vec<(u16, u16)> = iproduct!(0..=23, 500..=params.max_frame_sum).collect();
+
         'time_sum_loop: for time_sum in 0..=0xff {
-            for (hour, frame_sum) in iproduct!(0..=23, 500..=params.max_frame_sum) {
-                let initial_seed =
-                    (time_sum as Seed) << 24 | (hour as Seed) << 12 | frame_sum as Seed;
-                let mut rng_mt = RngMT::new(initial_seed);
-
-                for advances in 0..=10 {
-                    let next_seed = rng_mt.next();
-                    let pid = rng_mt.get_pid(next_seed);
-
-                    let nature_num = (pid % 25) as i16;
-                    let gender_num = (pid & 0xff) as i16;
-                    let ability_num = (pid & 1) as i16;
-                    let is_shiny = {
-                        let tsid_xor = (params.tid ^ params.sid) as u32;
-                        let pid_xor = ((pid >> 16) ^ (pid & 0xffff)) as u32;
-                        (tsid_xor ^ pid_xor) <= 7
-                    };
+            // MT_LANES 個ずつまとめて、引き直しの分も含めた最初の出力を求める (端数は最後の要素で埋める)
+            for chunk in candidates.chunks(MT_LANES) {
+                let initial_seeds: [InitialSeed; MT_LANES] = std::array::from_fn(|lane| {
+                    let (hour, frame_sum) = chunk[lane.min(chunk.len() - 1)];
+                    (time_sum as Seed) << 24 | (hour as Seed) << 16 | frame_sum as Seed
+                });
+                let pids = rng_mt_batch.pids(&initial_seeds);
 
-                    let check_nature = params.nature == -1 || params.nature == nature_num;
-                    let check_ability = params.ability == -1 || params.ability == ability_num;
-                    let check_shiny = params.shiny == false || (params.shiny && is_shiny);
+                for (lane, (hour, frame_sum)) in chunk.iter().enumerate() {
+                    for advances in 0..=10 {
+                        let outputs = pids[advances as usize..].iter().map(|lanes| lanes[lane]);
+                        let (pid, mt_calls, nature_inherited) = match self.seed_analyzer.egg_pid(
+                            outputs,
+                            params.international,
+                            params.everstone_nature,
+                            params.tid,
+                            params.sid,
+                        ) {
+                            Some(egg_pid) => egg_pid,
+                            None => {
+                                let mut mt = RngMT::new(initial_seeds[lane]);
+                                for _ in 0..advances {
+                                    mt.next();
+                                }
+                                let outputs = std::iter::from_fn(|| {
+                                    let next_seed = mt.next();
+                                    Some(mt.get_pid(next_seed))
+                                })
+                                .take(EVERSTONE_MAX_CALLS);
 
-                    if check_nature && check_ability && check_shiny {
-                        result.push(ReturnParams {
-                            initial_seed: None,
-                            ivs: None,
-                            pid: Some(pid),
-                            nature: Some(nature_num as u8),
-                            gender: Some(gender_num as u8),
-                            ability: Some(ability_num as u8),
-                            advances: Some(advances),
-                            time_sum: Some(time_sum),
-                            hour: Some(hour),
-                            frame_sum: Some(frame_sum),
-                        });
-                        continue 'time_sum_loop;
+                                match self.seed_analyzer.egg_pid(
+                                    outputs,
+                                    params.international,
+                                    params.everstone_nature,
+                                    params.tid,
+                                    params.sid,
+                                ) {
+                                    Some(egg_pid) => egg_pid,
+                                    None => continue,
+                                }
+                            }
+                        };
+
+                        let nature_num = (pid % 25) as i16;
+                        let gender_num = (pid & 0xff) as i16;
+                        let species = params.species.map(|species| egg_species(species, pid));
+                        let gender = resolve_gender(species, pid);
+                        let ability_num = (pid & 1) as i16;
+                        let is_shiny = {
+                            let tsid_xor = (params.tid ^ params.sid) as u32;
+                            let pid_xor = ((pid >> 16) ^ (pid & 0xffff)) as u32;
+                            (tsid_xor ^ pid_xor) <= 7
+                        };
+
+                        let check_nature = params.nature == -1 || params.nature == nature_num;
+                        let check_ability = params.ability == -1 || params.ability == ability_num;
+                        let check_shiny = params.shiny == false || (params.shiny && is_shiny);
+                        let check_gender = params.gender.is_none_or(|g| gender == Some(g));
+
+                        if check_nature && check_ability && check_shiny && check_gender {
+                            result.push(ReturnParams {
+                                initial_seed: Some(initial_seeds[lane]),
+                                ivs: None,
+                                pid: Some(pid),
+                                pid_traits: Some(PidTraits::decode(pid, species)),
+                                nature: Some(nature_num as u8),
+                                gender,
+                                gender_value: Some(gender_num as u8),
+                                ability: Some(ability_num as u8),
+                                advances: Some(advances),
+                                time_sum: Some(time_sum),
+                                hour: Some(*hour),
+                                frame_sum: Some(*frame_sum),
+                                species,
+                                mt_calls: Some(mt_calls),
+                                nature_inherited: Some(nature_inherited),
+                                ..Default::default()
+                            });
+                            continue 'time_sum_loop;
+                        }
                     }
                 }
             }
@@ -226,9 +762,18 @@ impl SeedSearcher {
         params: SearchParams,
         parent_ivs_0: IVs,
         parent_ivs_1: IVs,
+        version: GameVersion,
     ) -> Vec<ReturnParams> {
         let mut result: Vec<ReturnParams> = Vec::new();
 
+        // 個性は遺伝後の個体値で決まるので、範囲の中に個性の条件を満たす個体値がなければ探索しない
+        let narrowed_iv_ranges = params
+            .characteristic
+            .map(|characteristic| characteristic.narrow_iv_ranges(&params.iv_ranges));
+        if let Some(None) = narrowed_iv_ranges {
+            return result;
+        }
+
         let iv_range_group_1 = [
             params.iv_ranges.hp.clone(),
             params.iv_ranges.attack.clone(),
@@ -310,43 +855,13 @@ impl SeedSearcher {
                     parent_ivs_1.sp_defense,
                 ];
 
-                // 0: hp | 1: attack | 2: defense | 3: speed | 4: sp_attack | 5: sp_defense
-                let mut gene_loci: Vec<u8> = [0, 1, 2, 3, 4, 5].to_vec();
-                let parents_ivs = [parent_ivs_0, parent_ivs_1];
-
-                let gene_locus_seed_1 = self.rng_lc.next(iv_2nd_seed);
-                let gene_locus_seed_2 = self.rng_lc.next(gene_locus_seed_1);
-                let gene_locus_seed_3 = self.rng_lc.next(gene_locus_seed_2);
-                let gene_parent_seed_1 = self.rng_lc.next(gene_locus_seed_3);
-                let gene_parent_seed_2 = self.rng_lc.next(gene_parent_seed_1);
-                let gene_parent_seed_3 = self.rng_lc.next(gene_parent_seed_2);
-
-                let gene_locus_rand_1 = self.rng_analyzer.extract_rand(gene_locus_seed_1);
-                let gene_locus_rand_2 = self.rng_analyzer.extract_rand(gene_locus_seed_2);
-                let gene_locus_rand_3 = self.rng_analyzer.extract_rand(gene_locus_seed_3);
-                let gene_parent_rand_1 = self.rng_analyzer.extract_rand(gene_parent_seed_1);
-                let gene_parent_rand_2 = self.rng_analyzer.extract_rand(gene_parent_seed_2);
-                let gene_parent_rand_3 = self.rng_analyzer.extract_rand(gene_parent_seed_3);
-
-                let gene_locus_index_1 = (gene_locus_rand_1 % 6) as usize; // gene_loci のインデックス番号
-                let gene_locus_index_2 = (gene_locus_rand_2 % 5) as usize; // gene_loci のインデックス番号
-                let gene_locus_index_3 = (gene_locus_rand_3 % 4) as usize; // gene_loci のインデックス番号
-                let gene_parent_num_1 = (gene_parent_rand_1 % 2) as usize; // 参照する親番号 (0 -> 先親 | 1 -> 後親)
-                let gene_parent_num_2 = (gene_parent_rand_2 % 2) as usize; // 参照する親番号 (0 -> 先親 | 1 -> 後親)
-                let gene_parent_num_3 = (gene_parent_rand_3 % 2) as usize; // 参照する親番号 (0 -> 先親 | 1 -> 後親)
-
-                let gene_locus_1 = gene_loci[gene_locus_index_1] as usize;
-                gene_loci.remove(gene_locus_index_1);
-
-                let gene_locus_2 = gene_loci[gene_locus_index_2] as usize;
-                gene_loci.remove(gene_locus_index_2);
-
-                let gene_locus_3 = gene_loci[gene_locus_index_3] as usize;
-                gene_loci.remove(gene_locus_index_3);
-
-                ivs[gene_locus_1] = parents_ivs[gene_parent_num_1][gene_locus_1];
-                ivs[gene_locus_2] = parents_ivs[gene_parent_num_2][gene_locus_2];
-                ivs[gene_locus_3] = parents_ivs[gene_parent_num_3][gene_locus_3];
+                let (inherited_ivs, iv_sources) = self.egg_analyzer.inherit_ivs(
+                    iv_2nd_seed,
+                    ivs,
+                    [parent_ivs_0, parent_ivs_1],
+                    version,
+                );
+                let ivs = inherited_ivs;
 
                 let all_ivs_contains_range = [
                     iv_range_group_1[0].clone(),
@@ -370,6 +885,10 @@ impl SeedSearcher {
                         sp_defense: ivs[5],
                     };
 
+                    if !params.check_hidden_power(&ivs) || !params.check_egg_characteristic(&ivs) {
+                        continue;
+                    }
+
                     let initial_seed_data = self.search_initial_seed(
                         iv_1st_seed,
                         params.max_advances,
@@ -380,14 +899,16 @@ impl SeedSearcher {
                         result.push(ReturnParams {
                             initial_seed: Some(init_seed_data.0),
                             ivs: Some(ivs),
+                            hidden_power: Some(ivs.hidden_power()),
                             pid: None,
                             nature: None,
-                            gender: None,
                             ability: None,
                             advances: Some(init_seed_data.1),
                             time_sum: Some(init_seed_data.2),
                             hour: Some(init_seed_data.3),
                             frame_sum: Some(init_seed_data.4),
+                            iv_sources: Some(iv_sources),
+                            ..Default::default()
                         });
                     }
                 }
@@ -399,6 +920,8 @@ impl SeedSearcher {
 
     /**
      * Option<(InitialSeed, u16, u16, u16, u16)> -> Option(initial_seed, advances, time_sum, hour, frame_sum)
+     * seed は最初に乱数を取り出すシードなので、その 1 つ前が消費数 0 の初期シードになる。
+     * 初期シードとしてあり得る値になるまで 1 つずつ遡る。
      */
     pub fn search_initial_seed(
         &self,
@@ -406,10 +929,9 @@ impl SeedSearcher {
         max_advances: u16,
         max_frame_sum: u16,
     ) -> Option<(InitialSeed, u16, u16, u16, u16)> {
-        let mut initial_seed = seed;
-        let mut advances: u16 = 0; // 消費数
+        let mut initial_seed = self.rng_lc.prev(seed);
 
-        while (0..=max_advances).contains(&advances) {
+        for advances in 0..=max_advances {
             let time_sum = ((initial_seed >> 24) & 0xff) as u16;
             let hour = ((initial_seed >> 16) & 0xff) as u16;
             let frame_sum = (initial_seed & 0xffff) as u16;
@@ -418,16 +940,456 @@ impl SeedSearcher {
             let check_hour = hour > 23;
             let check_frame_sum = (600..=max_frame_sum + 99).contains(&frame_sum) == false; // 99 はDSで設定できる最大の年
 
-            if check_time_sum || check_hour || check_frame_sum {
-                continue;
-            } else {
-                initial_seed = self.rng_lc.prev(initial_seed);
-                advances += 1;
+            if !(check_time_sum || check_hour || check_frame_sum) {
+                return Some((initial_seed, advances, time_sum, hour, frame_sum));
             }
 
-            return Some((initial_seed, advances, time_sum, hour, frame_sum));
+            initial_seed = self.rng_lc.prev(initial_seed);
         }
 
         return None;
     }
 }
+
+/*
+    種族が分かる (図鑑にある) 場合だけ、その性別の閾値で性別を決める。
+*/
+fn resolve_gender(species: Option<u16>, pid: PID) -> Option<Gender> {
+    return species
+        .and_then(gender_ratio)
+        .map(|ratio| Gender::from_pid(pid, ratio));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 省略できる項目を省いた SearchParams (個体値は全て 31)
+    fn search_params() -> SearchParams {
+        let json = r#"{
+            "iv_ranges": {
+                "hp": { "start": 31, "end": 31 },
+                "attack": { "start": 31, "end": 31 },
+                "defense": { "start": 31, "end": 31 },
+                "speed": { "start": 31, "end": 31 },
+                "sp_attack": { "start": 31, "end": 31 },
+                "sp_defense": { "start": 31, "end": 31 }
+            },
+            "nature": -1,
+            "ability": -1,
+            "shiny": false,
+            "tid": 0,
+            "sid": 0,
+            "max_advances": 100,
+            "max_frame_sum": 3000
+        }"#;
+        return serde_json::from_str(json).unwrap();
+    }
+
+    // ivs だけを含む IVRanges
+    fn exact_iv_ranges(ivs: &IVs) -> IVRanges {
+        return IVRanges {
+            hp: ivs.hp..=ivs.hp,
+            attack: ivs.attack..=ivs.attack,
+            defense: ivs.defense..=ivs.defense,
+            speed: ivs.speed..=ivs.speed,
+            sp_attack: ivs.sp_attack..=ivs.sp_attack,
+            sp_defense: ivs.sp_defense..=ivs.sp_defense,
+        };
+    }
+
+    #[test]
+    fn search_params_default_test() {
+        let params = search_params();
+
+        assert_eq!(params.methods, vec![Method::Method1]);
+        assert_eq!(params.lead, Lead::None);
+        assert!(params.encounter_slots.is_empty() && params.hidden_power_types.is_empty());
+        assert_eq!(params.held_item, None);
+        assert!(!params.international && !params.stationary);
+
+        let params = SearchParams {
+            methods: Vec::new(),
+            ..params
+        };
+        assert_eq!(params.methods(), &[Method::Method1]);
+    }
+
+    #[test]
+    fn stationary_test() {
+        let searcher = SeedSearcher::new();
+        let wild_analyzer = WildAnalyzer::new();
+        let rng_lc = RngLC::new();
+        let initial_seed: InitialSeed = 0x0c030b3a;
+
+        for (version, lead) in iproduct!(
+            [GameVersion::Platinum, GameVersion::HeartGoldSoulSilver],
+            [Lead::None, Lead::Synchronize(3)]
+        ) {
+            let wild_params = WildParams {
+                method: version.wild_method(),
+                encounter_type: EncounterType::Static,
+                area: None,
+            };
+
+            for advances in 0..8u32 {
+                let origin_seed = rng_lc.advance(initial_seed, advances);
+                let wild_status =
+                    wild_analyzer.generate_wild_status(origin_seed, &wild_params, lead, 0, 0);
+                let ivs = wild_status.status.ivs;
+                let params = SearchParams {
+                    iv_ranges: exact_iv_ranges(&ivs),
+                    nature: wild_status.status.nature as i16,
+                    lead,
+                    stationary: true,
+                    ..search_params()
+                };
+
+                // Method1 の並びで探すと、同じ個体でも性格の乱数の分だけ生成が始まる位置がずれる
+                let results = searcher.search_seeds_from_status(
+                    SearchParams {
+                        stationary: false,
+                        ..params.clone()
+                    },
+                    version,
+                );
+                assert!(results.iter().all(|result| {
+                    rng_lc.advance(
+                        result.initial_seed.unwrap(),
+                        result.advances.unwrap() as u32,
+                    ) != origin_seed
+                }));
+
+                let results = searcher.search_seeds_from_status(params.clone(), version);
+                assert!(results.iter().any(|result| {
+                    result.pid == Some(wild_status.status.pid)
+                        && result.synchronized == Some(wild_status.synchronized)
+                        && rng_lc.advance(
+                            result.initial_seed.unwrap(),
+                            result.advances.unwrap() as u32,
+                        ) == origin_seed
+                }));
+
+                let params = SearchParams {
+                    methods: vec![Method::Method2],
+                    ..params
+                };
+                assert!(
+                    searcher
+                        .search_seeds_from_status(params, version)
+                        .is_empty()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cute_charm_filter_test() {
+        let searcher = SeedSearcher::new();
+        let wild_analyzer = WildAnalyzer::new();
+        let rng_lc = RngLC::new();
+        let lead = Lead::CuteCharm {
+            female_lead: true,
+            gender_ratio: 0x7f,
+        };
+        let wild_params = WildParams {
+            method: WildMethod::MethodK,
+            encounter_type: EncounterType::Grass,
+            area: None,
+        };
+
+        // メロメロボディが発動するフレームを探す
+        let (origin_seed, wild_status) = (0..100u32)
+            .map(|advances| rng_lc.advance(0x0c030b3au32, advances))
+            .map(|seed| {
+                let wild_status =
+                    wild_analyzer.generate_wild_status(seed, &wild_params, lead, 0, 0);
+                (seed, wild_status)
+            })
+            .find(|(_, wild_status)| wild_status.cute_charmed)
+            .unwrap();
+        assert_eq!(
+            wild_status.status.pid,
+            0x96 + wild_status.status.nature as PID
+        );
+
+        let ivs = wild_status.status.ivs;
+        let params = SearchParams {
+            iv_ranges: exact_iv_ranges(&ivs),
+            lead,
+            cute_charmed: true,
+            ..search_params()
+        };
+
+        let results = searcher.search_seeds_from_wild_status(params, wild_params);
+        assert!(
+            results
+                .iter()
+                .all(|result| result.cute_charmed == Some(true))
+        );
+        assert!(results.iter().any(|result| {
+            rng_lc.advance(
+                result.initial_seed.unwrap(),
+                result.advances.unwrap() as u32,
+            ) == origin_seed
+        }));
+    }
+
+    #[test]
+    fn held_item_filter_test() {
+        let searcher = SeedSearcher::new();
+        let wild_analyzer = WildAnalyzer::new();
+        let rng_lc = RngLC::new();
+        let wild_params = WildParams {
+            method: WildMethod::MethodJ,
+            encounter_type: EncounterType::Grass,
+            area: None,
+        };
+
+        // ふくがんで 5% の持ち物になるフレームを探す
+        let wild_status = (0..100u32)
+            .map(|advances| rng_lc.advance(0x0c030b3au32, advances))
+            .map(|seed| {
+                wild_analyzer.generate_wild_status(seed, &wild_params, Lead::CompoundEyes, 0, 0)
+            })
+            .find(|wild_status| wild_status.held_item == HeldItem::Rare)
+            .unwrap();
+
+        let ivs = wild_status.status.ivs;
+        let params = SearchParams {
+            iv_ranges: exact_iv_ranges(&ivs),
+            lead: Lead::CompoundEyes,
+            held_item: Some(HeldItem::Rare),
+            ..search_params()
+        };
+
+        let results = searcher.search_seeds_from_wild_status(params.clone(), wild_params.clone());
+        assert!(
+            results
+                .iter()
+                .all(|result| result.held_item == Some(HeldItem::Rare))
+        );
+        assert!(results.iter().any(|result| {
+            rng_lc.advance(
+                result.initial_seed.unwrap(),
+                result.advances.unwrap() as u32,
+            ) == wild_status.origin_seed
+        }));
+
+        let params = SearchParams {
+            held_item: Some(HeldItem::None),
+            ..params
+        };
+        assert!(
+            searcher
+                .search_seeds_from_wild_status(params, wild_params)
+                .iter()
+                .all(|result| result.held_item == Some(HeldItem::None))
+        );
+    }
+
+    #[test]
+    fn roamer_search_test() {
+        let searcher = SeedSearcher::new();
+        let roamer_analyzer = RoamerAnalyzer::new();
+        let rng_lc = RngLC::new();
+        let origin_seed = rng_lc.advance(0x0c030b3au32, 5u32);
+
+        for roamer in [Roamer::Raikou, Roamer::Cresselia] {
+            let pid = roamer_analyzer
+                .generate_roamer_status(origin_seed, roamer, 0, 0)
+                .status
+                .pid;
+            // 切り詰められる場合は候補が多く上限で打ち切られるので、色違いになる裏IDで絞る
+            let sid = ((pid >> 16) ^ (pid & 0xffff)) as Rand;
+            let status = roamer_analyzer
+                .generate_roamer_status(origin_seed, roamer, 0, sid)
+                .status;
+            let params = SearchParams {
+                iv_ranges: exact_iv_ranges(&status.ivs),
+                nature: status.nature as i16,
+                shiny: true,
+                sid,
+                ..search_params()
+            };
+
+            let results = searcher.search_seeds_from_roamer_status(params, roamer);
+            assert!(results.iter().any(|result| {
+                result.pid == Some(status.pid)
+                    && result.species == Some(roamer.species())
+                    && rng_lc.advance(
+                        result.initial_seed.unwrap(),
+                        result.advances.unwrap() as u32,
+                    ) == origin_seed
+            }));
+        }
+    }
+
+    #[test]
+    fn roamer_search_cap_test() {
+        let searcher = SeedSearcher::new();
+        let params = SearchParams {
+            iv_ranges: IVRanges {
+                hp: 0..=31,
+                attack: 0..=31,
+                defense: 0..=31,
+                speed: 0..=31,
+                sp_attack: 0..=31,
+                sp_defense: 0..=31,
+            },
+            ability: 1,
+            ..search_params()
+        };
+
+        // 条件が緩くても上限で打ち切られ、打ち切るまでの結果は条件を満たす
+        let results = searcher.search_seeds_from_roamer_status(params, Roamer::Mesprit);
+        assert_eq!(results.len(), MAX_ROAMER_RESULTS);
+        assert!(results.iter().all(|result| result.ability == Some(1)));
+    }
+
+    #[test]
+    fn egg_pid_search_test() {
+        use crate::modules::seed_analyzer::MASUDA_REROLLS;
+
+        let searcher = SeedSearcher::new();
+        let arng = RngLC::new_arng();
+        let mt_outputs = |initial_seed: InitialSeed, count: usize| -> Vec<PID> {
+            let mut mt = RngMT::new(initial_seed);
+            return (0..count)
+                .map(|_| {
+                    let next_seed = mt.next();
+                    mt.get_pid(next_seed)
+                })
+                .collect();
+        };
+
+        // かわらずのいし: 遺伝したなら最初の出力の最上位 bit が 0 で、最後の出力が性格の一致する PID
+        let params = SearchParams {
+            max_frame_sum: 500,
+            nature: 3,
+            everstone_nature: Some(3),
+            ..search_params()
+        };
+        let results = searcher.search_seeds_from_egg_pid(params);
+        assert!(!results.is_empty());
+        for result in &results {
+            let (advances, mt_calls) = (result.advances.unwrap(), result.mt_calls.unwrap());
+            let outputs = mt_outputs(
+                result.initial_seed.unwrap(),
+                advances as usize + mt_calls as usize,
+            );
+            let pid = result.pid.unwrap();
+
+            assert_eq!(pid % 25, 3);
+            assert_eq!(outputs.last(), Some(&pid));
+            assert_eq!(
+                outputs[advances as usize] >> 31 == 0,
+                result.nature_inherited.unwrap()
+            );
+        }
+
+        // 国際孵化: MT は 1 回だけ消費し、その出力を ARNG で引き直して色違いになる
+        let params = SearchParams {
+            max_frame_sum: 500,
+            shiny: true,
+            international: true,
+            tid: 12345,
+            sid: 54321,
+            ..search_params()
+        };
+        let results = searcher.search_seeds_from_egg_pid(params);
+        assert!(!results.is_empty());
+        for result in &results {
+            let advances = result.advances.unwrap() as usize;
+            let output = mt_outputs(result.initial_seed.unwrap(), advances + 1)[advances];
+
+            assert_eq!(result.mt_calls, Some(1));
+            assert!(
+                (0..=MASUDA_REROLLS as u32)
+                    .any(|rerolls| arng.advance(output, rerolls) == result.pid.unwrap())
+            );
+        }
+
+        // 性格の範囲外は探索しない
+        let params = SearchParams {
+            max_frame_sum: 500,
+            everstone_nature: Some(25),
+            ..search_params()
+        };
+        assert!(searcher.search_seeds_from_egg_pid(params).is_empty());
+    }
+
+    #[test]
+    fn egg_iv_characteristic_test() {
+        let searcher = SeedSearcher::new();
+        let parent_ivs = IVs {
+            hp: 0,
+            attack: 0,
+            defense: 0,
+            speed: 0,
+            sp_attack: 0,
+            sp_defense: 0,
+        };
+        let search = |characteristic| {
+            let params = SearchParams {
+                characteristic,
+                ..search_params()
+            };
+            return searcher.search_seeds_from_egg_iv(
+                params,
+                parent_ivs,
+                parent_ivs,
+                GameVersion::HeartGoldSoulSilver,
+            );
+        };
+
+        // 遺伝で HP が 0 になると、HP の個性 (31 % 5 == 1) にはならない
+        let results = search(None);
+        assert!(results.iter().any(|result| result.ivs.unwrap().hp == 0));
+
+        let hp_characteristic = Characteristic { stat: 0, value: 1 };
+        let narrowed = search(Some(hp_characteristic));
+        assert!(!narrowed.is_empty());
+        assert!(narrowed.len() < results.len());
+        assert!(narrowed.iter().all(|result| result.ivs.unwrap().hp == 31));
+
+        // 個体値がすべて 31 なら 5 で割った余りが 0 の個性はありえない
+        assert!(search(Some(Characteristic { stat: 0, value: 0 })).is_empty());
+    }
+
+    #[test]
+    fn resolve_gender_test() {
+        assert_eq!(resolve_gender(Some(29), 0xffffffff), Some(Gender::Female));
+        assert_eq!(
+            resolve_gender(Some(81), 0x00000000),
+            Some(Gender::Genderless)
+        );
+        assert_eq!(resolve_gender(Some(0), 0x00000000), None);
+        assert_eq!(resolve_gender(Some(600), 0x00000000), None);
+        assert_eq!(resolve_gender(None, 0x00000000), None);
+    }
+
+    #[test]
+    fn search_initial_seed_test() {
+        let searcher = SeedSearcher::new();
+        let rng_lc = RngLC::new();
+        let initial_seed: InitialSeed = 0x0c030b3a;
+
+        // 消費数 0 なら最初に乱数を取り出すシードは初期シードの次
+        let first_seed = rng_lc.next(initial_seed);
+        assert_eq!(
+            searcher.search_initial_seed(first_seed, 0, 2800),
+            Some((initial_seed, 0, 0x0c, 0x03, 0x0b3a))
+        );
+
+        let first_seed = rng_lc.advance(initial_seed, 151u32);
+        let (found_seed, advances, ..) = searcher
+            .search_initial_seed(first_seed, 1000, 2800)
+            .unwrap();
+        assert!(advances <= 150);
+        assert_eq!(rng_lc.advance(found_seed, advances as u32 + 1), first_seed);
+
+        // 範囲内に初期シードとしてあり得る値がなければ None
+        assert_eq!(searcher.search_initial_seed(first_seed, 1000, 600), None);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    constants::species,
+    types::{iv::*, stats::*},
+};
+
+const SHEDINJA: u16 = 292;
+
+/*
+    能力値の計算 (stat: 0: hp | 1: attack | 2: defense | 3: speed | 4: sp_attack | 5: sp_defense)
+    HP: (種族値 * 2 + 個体値 + 努力値 / 4) * レベル / 100 + レベル + 10
+    その他: ((種族値 * 2 + 個体値 + 努力値 / 4) * レベル / 100 + 5) * 性格補正
+*/
+pub fn calc_stat(stat: usize, base: u8, iv: IV, ev: u16, level: u8, nature: u8) -> u16 {
+    let level = level as u32;
+    let raw = (base as u32 * 2 + iv as u32 + ev as u32 / 4) * level / 100;
+
+    if stat == 0 {
+        return (raw + level + 10) as u16;
+    }
+
+    return ((raw + 5) * nature_modifier(stat, nature) / 100) as u16;
+}
+
+/*
+    性格補正 (%)。性格番号 / 5 の能力が上がり、性格番号 % 5 の能力が下がる (attack, defense, speed, sp_attack, sp_defense の順)。
+*/
+fn nature_modifier(stat: usize, nature: u8) -> u32 {
+    let increased = (nature / 5) as usize + 1;
+    let decreased = (nature % 5) as usize + 1;
+
+    return match stat {
+        _ if increased == decreased => 100,
+        _ if stat == increased => 110,
+        _ if stat == decreased => 90,
+        _ => 100,
+    };
+}
+
+/*
+    種族/性格と、1 つ以上のレベルで確認した能力値から、すべてに矛盾しない個体値の範囲を求める。
+    範囲は条件を満たす個体値の最小値から最大値まで (間に条件を満たさない値を含むことがある)。
+    種族が分からない場合や、どの個体値でも能力値が合わない場合は None。
+*/
+pub fn calc_iv_ranges(
+    species: u16,
+    nature: u8,
+    observations: &[StatObservation],
+) -> Option<IVRanges> {
+    let base_stats = species::by_dex(species)?.base_stats;
+    let bases = [
+        base_stats.hp,
+        base_stats.attack,
+        base_stats.defense,
+        base_stats.speed,
+        base_stats.sp_attack,
+        base_stats.sp_defense,
+    ];
+
+    let mut ranges: [IVRange; 6] = std::array::from_fn(|_| 0..=31);
+
+    for (stat, base) in bases.iter().enumerate() {
+        // ヌケニンの HP は常に 1 なので個体値は分からない
+        if stat == 0 && species == SHEDINJA {
+            continue;
+        }
+
+        let candidates: Vec<IV> = (0..=31)
+            .filter(|iv| {
+                observations.iter().all(|observation| {
+                    let stats = observation.stats.to_array();
+                    let evs = observation.evs.to_array();
+                    calc_stat(stat, *base, *iv, evs[stat], observation.level, nature) == stats[stat]
+                })
+            })
+            .collect();
+
+        ranges[stat] = *candidates.first()?..=*candidates.last()?;
+    }
+
+    let [hp, attack, defense, speed, sp_attack, sp_defense] = ranges;
+
+    return Some(IVRanges {
+        hp,
+        attack,
+        defense,
+        speed,
+        sp_attack,
+        sp_defense,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calc_iv_ranges_test() {
+        // ガブリアス Lv.78 いじっぱり (個体値 24/12/30/5/16/23)
+        let evs = Stats {
+            hp: 74,
+            attack: 190,
+            defense: 91,
+            speed: 23,
+            sp_attack: 48,
+            sp_defense: 84,
+        };
+        let stats = Stats {
+            hp: 289,
+            attack: 278,
+            defense: 193,
+            speed: 171,
+            sp_attack: 135,
+            sp_defense: 171,
+        };
+        let observations = [StatObservation {
+            level: 78,
+            stats,
+            evs,
+        }];
+
+        let iv_ranges = calc_iv_ranges(445, 3, &observations).unwrap();
+        assert!(iv_ranges.hp.contains(&24) && iv_ranges.hp.end() - iv_ranges.hp.start() <= 1);
+        assert!(iv_ranges.attack.contains(&12));
+        assert!(iv_ranges.defense.contains(&30));
+        assert!(iv_ranges.speed.contains(&5));
+        assert!(iv_ranges.sp_attack.contains(&16));
+        assert!(iv_ranges.sp_defense.contains(&23));
+
+        // 能力値が合わなければ None
+        let impossible = [StatObservation {
+            level: 78,
+            stats: Stats { hp: 400, ..stats },
+            evs,
+        }];
+        assert!(calc_iv_ranges(445, 3, &impossible).is_none());
+
+        // レベルが低いほど範囲は広く、複数のレベルを合わせると狭くなる
+        let level_5 = StatObservation {
+            level: 5,
+            stats: Stats {
+                hp: calc_stat(0, 108, 24, 0, 5, 3),
+                attack: calc_stat(1, 130, 12, 0, 5, 3),
+                defense: calc_stat(2, 95, 30, 0, 5, 3),
+                speed: calc_stat(3, 102, 5, 0, 5, 3),
+                sp_attack: calc_stat(4, 80, 16, 0, 5, 3),
+                sp_defense: calc_stat(5, 85, 23, 0, 5, 3),
+            },
+            evs: Stats {
+                hp: 0,
+                attack: 0,
+                defense: 0,
+                speed: 0,
+                sp_attack: 0,
+                sp_defense: 0,
+            },
+        };
+        let wide = calc_iv_ranges(445, 3, &[level_5]).unwrap();
+        let narrow = calc_iv_ranges(445, 3, &[level_5, observations[0]]).unwrap();
+        assert!(wide.hp.end() - wide.hp.start() > narrow.hp.end() - narrow.hp.start());
+        assert!(narrow.hp.contains(&24));
+    }
+}
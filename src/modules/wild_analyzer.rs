@@ -0,0 +1,529 @@
+use crate::{
+    constants::encounter_slots,
+    types::{gender::*, lead::*, seed::*, wild::*},
+};
+
+use super::{
+    rand_analyzer::RandAnalyzer,
+    rng::Rng,
+    rng_lc::{RngLC, RngLCStream},
+    seed_analyzer::SeedAnalyzer,
+};
+
+pub struct WildAnalyzer {
+    rng_analyzer: RandAnalyzer,
+    rng_lc: RngLC,
+    seed_analyzer: SeedAnalyzer,
+}
+
+impl WildAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            rng_analyzer: RandAnalyzer::new(),
+            rng_lc: RngLC::new(),
+            seed_analyzer: SeedAnalyzer::new(),
+        }
+    }
+
+    /*
+        野生ポケモンの生成 (Method J/K)
+        Method J と K は出現スロットと性格の乱数の使い方 (slot_value, nature_from_rand) だけが異なる。
+        出現スロット -> (レベル) -> (シンクロ判定) -> 性格 -> PID下位/PID上位 (pid % 25 が性格と一致するまで繰り返し) -> 個体値1 -> 個体値2
+        シンクロ判定に成功した場合は性格の乱数を消費せず、シンクロの性格になる。
+        個体値の後に持ち物の判定を行う。
+        メロメロボディの場合はシンクロ判定の位置でメロメロボディの判定を行い、成功すれば
+        PID を乱数で決めずに cute_charm_buffer + 性格 とし、性格の次がすぐ個体値になる。
+        性別が固定の種族にはメロメロボディの判定自体がなく (乱数も消費しない)、先頭なしと同じになる。
+        固定シンボル (Static) は出現スロットとレベルの乱数を消費しない。
+        origin_seed の次のシードから順に乱数を消費する。
+    */
+    pub fn generate_wild_status(
+        &self,
+        origin_seed: Seed,
+        wild_params: &WildParams,
+        lead: Lead,
+        tid: Rand,
+        sid: Rand,
+    ) -> WildStatus {
+        let mut rng = RngLCStream::new(self.rng_lc, origin_seed);
+
+        let slot_value = if wild_params.encounter_type.has_slot_rand() {
+            Some(slot_value(wild_params.method, rng.next_rand()))
+        } else {
+            None
+        };
+        let level_rand = if wild_params.encounter_type.has_level_rand() {
+            Some(rng.next_rand())
+        } else {
+            None
+        };
+
+        let encounter_slot = slot_value.and_then(|slot_value| {
+            encounter_slot(wild_params.method, wild_params.encounter_type, slot_value)
+        });
+        let area_slot = wild_params
+            .area
+            .as_ref()
+            .zip(encounter_slot)
+            .and_then(|(area, encounter_slot)| area.slots.get(encounter_slot as usize));
+        let species = area_slot.map(|area_slot| area_slot.species);
+        let level = area_slot.map(|area_slot| match level_rand {
+            Some(level_rand) => encounter_level(wild_params.method, area_slot, level_rand),
+            None => area_slot.min_level,
+        });
+
+        let charm_buffer = lead_cute_charm_buffer(lead);
+        let mut synchronized = false;
+        let mut cute_charmed = false;
+        let nature = match lead {
+            Lead::Synchronize(sync_nature) => {
+                synchronized = is_synchronized(wild_params.method, rng.next_rand());
+                if synchronized {
+                    sync_nature
+                } else {
+                    nature_from_rand(wild_params.method, rng.next_rand())
+                }
+            }
+            Lead::CuteCharm { .. } if charm_buffer.is_some() => {
+                cute_charmed = is_cute_charmed(wild_params.method, rng.next_rand());
+                nature_from_rand(wild_params.method, rng.next_rand())
+            }
+            _ => nature_from_rand(wild_params.method, rng.next_rand()),
+        };
+
+        let mut pid_rerolls: u32 = 0;
+        let pid = match charm_buffer {
+            Some(charm_buffer) if cute_charmed => charm_buffer + nature as PID,
+            _ => loop {
+                let pid_low = rng.next_rand() as PID;
+                let pid_high = rng.next_rand() as PID;
+                let pid = pid_high << 16 | pid_low;
+
+                if (pid % 25) as u8 == nature {
+                    break pid;
+                }
+
+                pid_rerolls += 1;
+            },
+        };
+
+        let iv_1st_rand = rng.next_rand();
+        let iv_2nd_rand = rng.next_rand();
+        let status = self
+            .seed_analyzer
+            .build_status(pid, iv_1st_rand, iv_2nd_rand, tid, sid);
+        let held_item = held_item(rng.next_rand(), lead == Lead::CompoundEyes);
+
+        return WildStatus {
+            status,
+            origin_seed,
+            slot_value,
+            encounter_slot,
+            species,
+            level,
+            level_rand,
+            synchronized,
+            cute_charmed,
+            pid_rerolls,
+            held_item,
+        };
+    }
+
+    /*
+        initial_seed から 0..=max_advances 消費したそれぞれのフレームで生成される野生ポケモンを列挙する。
+    */
+    pub fn list_wild_frames(
+        &self,
+        initial_seed: InitialSeed,
+        max_advances: u32,
+        wild_params: &WildParams,
+        lead: Lead,
+        tid: Rand,
+        sid: Rand,
+    ) -> Vec<(u32, WildStatus)> {
+        let mut frames: Vec<(u32, WildStatus)> = Vec::new();
+        let mut seed = initial_seed;
+
+        for advances in 0..=max_advances {
+            frames.push((
+                advances,
+                self.generate_wild_status(seed, wild_params, lead, tid, sid),
+            ));
+            seed = self.rng_lc.next(seed);
+        }
+
+        return frames;
+    }
+
+    /*
+        個体値1のシードから、その個体を生成しうる origin_seed を全て遡って求める。
+        PID の直前のシードが性格の乱数 (シンクロ成功ならシンクロ判定の乱数) であれば候補になる。
+        シンクロ失敗の場合は、性格の乱数のさらに前がシンクロ判定に失敗している必要がある。
+        さらにその 2 つを「捨てられた PID」とみなして遡れるのは、それが性格と一致しない場合だけ。
+    */
+    pub fn search_wild_origins(
+        &self,
+        iv_1st_seed: IV1stSeed,
+        wild_params: &WildParams,
+        lead: Lead,
+        tid: Rand,
+        sid: Rand,
+    ) -> Vec<WildStatus> {
+        let mut origins: Vec<WildStatus> = Vec::new();
+        let method = wild_params.method;
+        let charm_buffer = lead_cute_charm_buffer(lead);
+
+        // 性格 (またはシンクロ/メロメロボディ判定) より前に消費する乱数の数
+        let pre_advances = wild_params.encounter_type.has_slot_rand() as u32
+            + wild_params.encounter_type.has_level_rand() as u32;
+
+        // メロメロボディ成功時は 個体値1 の直前が性格、その前が判定の乱数
+        if charm_buffer.is_some() {
+            let charm_seed = self.rng_lc.rewind(iv_1st_seed, 2u32);
+            let charm_rand = self.rng_analyzer.extract_rand(charm_seed);
+
+            if is_cute_charmed(method, charm_rand) {
+                let origin_seed = self.rng_lc.rewind(charm_seed, pre_advances + 1);
+                origins.push(self.generate_wild_status(origin_seed, wild_params, lead, tid, sid));
+            }
+        }
+
+        let pid_2nd_seed = self.rng_lc.prev(iv_1st_seed);
+        let pid_1st_seed = self.rng_lc.prev(pid_2nd_seed);
+        let pid_1st_rand = self.rng_analyzer.extract_rand(pid_1st_seed) as PID;
+        let pid_2nd_rand = self.rng_analyzer.extract_rand(pid_2nd_seed) as PID;
+        let nature = ((pid_2nd_rand << 16 | pid_1st_rand) % 25) as u8;
+
+        let mut seed = pid_1st_seed;
+
+        loop {
+            let nature_seed = self.rng_lc.prev(seed);
+            let nature_rand = self.rng_analyzer.extract_rand(nature_seed);
+
+            // 性格を決めた乱数のシード (シンクロ/メロメロボディ失敗時は、その前の判定のシード) の一覧
+            let mut nature_origin_seeds: Vec<Seed> = Vec::new();
+
+            match lead {
+                Lead::Synchronize(sync_nature) => {
+                    if sync_nature == nature && is_synchronized(method, nature_rand) {
+                        nature_origin_seeds.push(nature_seed);
+                    }
+
+                    let sync_seed = self.rng_lc.prev(nature_seed);
+                    let sync_rand = self.rng_analyzer.extract_rand(sync_seed);
+                    if nature_from_rand(method, nature_rand) == nature
+                        && !is_synchronized(method, sync_rand)
+                    {
+                        nature_origin_seeds.push(sync_seed);
+                    }
+                }
+                Lead::CuteCharm { .. } if charm_buffer.is_some() => {
+                    let charm_seed = self.rng_lc.prev(nature_seed);
+                    let charm_rand = self.rng_analyzer.extract_rand(charm_seed);
+                    if nature_from_rand(method, nature_rand) == nature
+                        && !is_cute_charmed(method, charm_rand)
+                    {
+                        nature_origin_seeds.push(charm_seed);
+                    }
+                }
+                _ => {
+                    if nature_from_rand(method, nature_rand) == nature {
+                        nature_origin_seeds.push(nature_seed);
+                    }
+                }
+            }
+
+            for nature_origin_seed in nature_origin_seeds {
+                let origin_seed = self.rng_lc.rewind(nature_origin_seed, pre_advances + 1);
+                origins.push(self.generate_wild_status(origin_seed, wild_params, lead, tid, sid));
+            }
+
+            let rejected_pid_2nd_seed = nature_seed;
+            let rejected_pid_1st_seed = self.rng_lc.prev(rejected_pid_2nd_seed);
+            let rejected_pid = (self.rng_analyzer.extract_rand(rejected_pid_2nd_seed) as PID) << 16
+                | self.rng_analyzer.extract_rand(rejected_pid_1st_seed) as PID;
+
+            if (rejected_pid % 25) as u8 == nature {
+                break;
+            }
+
+            seed = rejected_pid_1st_seed;
+        }
+
+        return origins;
+    }
+}
+
+// 出現スロットを決める値 (0 ~ 99)
+pub fn slot_value(method: WildMethod, rand: Rand) -> u8 {
+    return match method {
+        WildMethod::MethodJ => (rand / 656) as u8,
+        WildMethod::MethodK => (rand % 100) as u8,
+    };
+}
+
+/*
+    slot_value (0 ~ 99) から出現スロットの番号を求める。
+    DPPt にはいわくだき/ずつきの野生データがないため None。
+*/
+pub fn encounter_slot(
+    method: WildMethod,
+    encounter_type: EncounterType,
+    slot_value: u8,
+) -> Option<u8> {
+    let rates: &[u8] = match (method, encounter_type) {
+        (_, EncounterType::Grass) => &encounter_slots::GRASS,
+        (_, EncounterType::Surfing) => &encounter_slots::SURFING,
+        (WildMethod::MethodJ, EncounterType::OldRod) => &encounter_slots::OLD_ROD_DPPT,
+        (_, EncounterType::OldRod | EncounterType::GoodRod | EncounterType::SuperRod) => {
+            &encounter_slots::ROD
+        }
+        (WildMethod::MethodK, EncounterType::RockSmash) => &encounter_slots::ROCK_SMASH,
+        (WildMethod::MethodK, EncounterType::Headbutt) => &encounter_slots::HEADBUTT,
+        _ => return None,
+    };
+
+    let mut threshold: u8 = 0;
+    for (slot, rate) in rates.iter().enumerate() {
+        threshold += rate;
+
+        if slot_value < threshold {
+            return Some(slot as u8);
+        }
+    }
+
+    return None;
+}
+
+/*
+    レベルの乱数からレベルを求める。
+    Method J は除算 (rand / (0xffff / 幅 + 1))、Method K は剰余 (rand % 幅) で幅の中から選ぶ。
+*/
+pub fn encounter_level(method: WildMethod, slot: &EncounterSlot, level_rand: Rand) -> u8 {
+    let range = (slot.max_level.saturating_sub(slot.min_level) as u16) + 1;
+    let offset = match method {
+        WildMethod::MethodJ => level_rand / (0xffff / range + 1),
+        WildMethod::MethodK => level_rand % range,
+    };
+
+    return slot.min_level + offset as u8;
+}
+
+/*
+    持ち物の判定。50% の持ち物、5% の持ち物、持っていない、のどれになるか。
+    先頭がふくがんなら 60% / 20% になる。
+*/
+pub fn held_item(rand: Rand, compound_eyes: bool) -> HeldItem {
+    let value = rand % 100;
+    let (common, rare) = if compound_eyes { (60, 80) } else { (50, 55) };
+
+    if value < common {
+        return HeldItem::Common;
+    }
+
+    if value < rare {
+        return HeldItem::Rare;
+    }
+
+    return HeldItem::None;
+}
+
+// シンクロ判定 (成功なら true)
+pub fn is_synchronized(method: WildMethod, rand: Rand) -> bool {
+    return match method {
+        WildMethod::MethodJ => rand >> 15 == 0,
+        WildMethod::MethodK => rand % 2 == 0,
+    };
+}
+
+// メロメロボディ判定 (成功なら true, 確率 2/3)
+pub fn is_cute_charmed(method: WildMethod, rand: Rand) -> bool {
+    return match method {
+        WildMethod::MethodJ => rand / 0x5556 != 0,
+        WildMethod::MethodK => rand % 3 != 0,
+    };
+}
+
+/*
+    メロメロボディ成功時の PID のベース値 (これに性格の値を足したものが PID になる)。
+    先頭が♂なら♀が出るように 0、先頭が♀なら♂が出るように性別の閾値を超える最小の 25 の倍数。
+    gender_ratio は性別の閾値 (0x1f: ♂7/8, 0x3f: ♂3/4, 0x7f: ♂1/2, 0xbf: ♂1/4)。
+    性別が固定 (MALE_ONLY / FEMALE_ONLY / GENDERLESS) ならメロメロボディは発動しないので None。
+*/
+pub fn cute_charm_buffer(female_lead: bool, gender_ratio: u8) -> Option<PID> {
+    if matches!(gender_ratio, MALE_ONLY | FEMALE_ONLY | GENDERLESS) {
+        return None;
+    }
+
+    if !female_lead {
+        return Some(0);
+    }
+
+    return Some(25 * ((gender_ratio as PID / 25) + 1));
+}
+
+// 先頭がメロメロボディで判定が行われる場合だけ cute_charm_buffer を返す
+fn lead_cute_charm_buffer(lead: Lead) -> Option<PID> {
+    return match lead {
+        Lead::CuteCharm {
+            female_lead,
+            gender_ratio,
+        } => cute_charm_buffer(female_lead, gender_ratio),
+        _ => None,
+    };
+}
+
+pub fn nature_from_rand(method: WildMethod, rand: Rand) -> u8 {
+    return match method {
+        WildMethod::MethodJ => (rand / 0xa3e) as u8,
+        WildMethod::MethodK => (rand % 25) as u8,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_wild_origins_test() {
+        let wild_analyzer = WildAnalyzer::new();
+        let rng_lc = RngLC::new();
+
+        for (method, encounter_type, lead) in [
+            (WildMethod::MethodJ, EncounterType::Surfing, Lead::None),
+            (WildMethod::MethodK, EncounterType::Grass, Lead::None),
+            (
+                WildMethod::MethodJ,
+                EncounterType::Grass,
+                Lead::Synchronize(3),
+            ),
+            (
+                WildMethod::MethodK,
+                EncounterType::Static,
+                Lead::Synchronize(3),
+            ),
+            (
+                WildMethod::MethodJ,
+                EncounterType::Grass,
+                Lead::CompoundEyes,
+            ),
+            (
+                WildMethod::MethodK,
+                EncounterType::Surfing,
+                Lead::CuteCharm {
+                    female_lead: true,
+                    gender_ratio: 0x7f,
+                },
+            ),
+        ] {
+            let wild_params = WildParams {
+                method,
+                encounter_type,
+                area: None,
+            };
+            let pre_advances = encounter_type.has_slot_rand() as u32
+                + encounter_type.has_level_rand() as u32
+                + matches!(lead, Lead::Synchronize(_) | Lead::CuteCharm { .. }) as u32;
+
+            let mut seed: Seed = 0x12345678;
+            for _ in 0..20 {
+                let wild_status =
+                    wild_analyzer.generate_wild_status(seed, &wild_params, lead, 0, 0);
+                // スロット + (レベル) + (判定) + (性格) + PID * (捨てた数 + 1) + 個体値1
+                let nature_advances = !wild_status.synchronized as u32;
+                let pid_advances = if wild_status.cute_charmed {
+                    0
+                } else {
+                    2 * (wild_status.pid_rerolls + 1)
+                };
+                let iv_1st_seed =
+                    rng_lc.advance(seed, pre_advances + nature_advances + pid_advances + 1);
+
+                let origins =
+                    wild_analyzer.search_wild_origins(iv_1st_seed, &wild_params, lead, 0, 0);
+                assert!(origins.iter().any(|origin| origin.origin_seed == seed));
+                assert!(
+                    origins
+                        .iter()
+                        .all(|origin| origin.status.ivs.hp == wild_status.status.ivs.hp)
+                );
+
+                seed = rng_lc.next(seed);
+            }
+        }
+    }
+
+    #[test]
+    fn held_item_test() {
+        assert_eq!(held_item(49, false), HeldItem::Common);
+        assert_eq!(held_item(50, false), HeldItem::Rare);
+        assert_eq!(held_item(54, false), HeldItem::Rare);
+        assert_eq!(held_item(55, false), HeldItem::None);
+        assert_eq!(held_item(149, false), HeldItem::Common);
+
+        // ふくがん
+        assert_eq!(held_item(59, true), HeldItem::Common);
+        assert_eq!(held_item(60, true), HeldItem::Rare);
+        assert_eq!(held_item(79, true), HeldItem::Rare);
+        assert_eq!(held_item(80, true), HeldItem::None);
+    }
+
+    #[test]
+    fn cute_charm_buffer_test() {
+        assert_eq!(cute_charm_buffer(true, 0x1f), Some(0x32));
+        assert_eq!(cute_charm_buffer(true, 0x3f), Some(0x4b));
+        assert_eq!(cute_charm_buffer(true, 0x7f), Some(0x96));
+        assert_eq!(cute_charm_buffer(true, 0xbf), Some(0xc8));
+        assert_eq!(cute_charm_buffer(false, 0x7f), Some(0));
+
+        // 性別が固定の種族には発動しない
+        for gender_ratio in [MALE_ONLY, FEMALE_ONLY, GENDERLESS] {
+            assert_eq!(cute_charm_buffer(true, gender_ratio), None);
+            assert_eq!(cute_charm_buffer(false, gender_ratio), None);
+        }
+    }
+
+    #[test]
+    fn cute_charm_fixed_gender_test() {
+        let wild_analyzer = WildAnalyzer::new();
+        let wild_params = WildParams {
+            method: WildMethod::MethodK,
+            encounter_type: EncounterType::Grass,
+            area: None,
+        };
+
+        // 性別が固定ならメロメロボディの乱数を消費せず、先頭なしと同じ個体になる
+        for gender_ratio in [MALE_ONLY, FEMALE_ONLY, GENDERLESS] {
+            let lead = Lead::CuteCharm {
+                female_lead: true,
+                gender_ratio,
+            };
+            let mut seed: Seed = 0x12345678;
+            for _ in 0..20 {
+                let charm_status =
+                    wild_analyzer.generate_wild_status(seed, &wild_params, lead, 0, 0);
+                let none_status =
+                    wild_analyzer.generate_wild_status(seed, &wild_params, Lead::None, 0, 0);
+                assert!(!charm_status.cute_charmed);
+                assert_eq!(charm_status.status.pid, none_status.status.pid);
+                assert_eq!(charm_status.status.ivs, none_status.status.ivs);
+
+                seed = RngLC::new().next(seed);
+            }
+        }
+    }
+
+    #[test]
+    fn encounter_slot_test() {
+        use EncounterType::*;
+        use WildMethod::*;
+
+        assert_eq!(encounter_slot(MethodJ, Grass, 0), Some(0));
+        assert_eq!(encounter_slot(MethodJ, Grass, 45), Some(2));
+        assert_eq!(encounter_slot(MethodK, Grass, 99), Some(11));
+        assert_eq!(encounter_slot(MethodJ, OldRod, 65), Some(1));
+        assert_eq!(encounter_slot(MethodK, OldRod, 85), Some(2));
+        assert_eq!(encounter_slot(MethodK, Headbutt, 97), Some(5));
+        assert_eq!(encounter_slot(MethodJ, RockSmash, 10), None);
+    }
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use super::{iv::*, seed::*};
+
+/*
+    個性 (「食べるのが大好き」など)
+    stat: 一番高い個体値の能力 (0: hp | 1: attack | 2: defense | 3: speed | 4: sp_attack | 5: sp_defense)
+    value: その個体値 % 5
+    一番高い個体値が複数ある場合は、PID % 6 の能力から上の順に巡って最初に見つかった能力になる。
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Characteristic {
+    pub stat: u8,
+    pub value: u8,
+}
+
+impl Characteristic {
+    pub fn from_ivs(ivs: &IVs, pid: PID) -> Self {
+        let ivs = iv_array(ivs);
+        let max_iv = *ivs.iter().max().unwrap();
+        let start = (pid % 6) as usize;
+
+        let stat = (0..6)
+            .map(|i| (start + i) % 6)
+            .find(|stat| ivs[*stat] == max_iv)
+            .unwrap();
+
+        return Self {
+            stat: stat as u8,
+            value: max_iv % 5,
+        };
+    }
+
+    /*
+        個性から分かる個体値の範囲。
+        個性の能力は value と 5 で割った余りが等しい範囲の中の値に、他の能力はその値以下に絞る。
+        PID が分からないので同じ値の能力は除外できない。条件を満たす個体値がなければ None。
+    */
+    pub fn narrow_iv_ranges(&self, iv_ranges: &IVRanges) -> Option<IVRanges> {
+        let mut ranges = range_array(iv_ranges);
+        let stat = self.stat as usize;
+
+        // 他の能力の下限以上で、5 で割った余りが value になる個体値
+        let others_min = (0..6)
+            .filter(|i| *i != stat)
+            .map(|i| *ranges[i].start())
+            .max()
+            .unwrap();
+        let candidates: Vec<IV> = ranges[stat]
+            .clone()
+            .filter(|iv| iv % 5 == self.value && others_min <= *iv)
+            .collect();
+
+        let (min, max) = (*candidates.first()?, *candidates.last()?);
+        ranges[stat] = min..=max;
+
+        for (i, range) in ranges.iter_mut().enumerate() {
+            if i != stat {
+                if max < *range.start() {
+                    return None;
+                }
+                *range = *range.start()..=(*range.end()).min(max);
+            }
+        }
+
+        let [hp, attack, defense, speed, sp_attack, sp_defense] = ranges;
+
+        return Some(IVRanges {
+            hp,
+            attack,
+            defense,
+            speed,
+            sp_attack,
+            sp_defense,
+        });
+    }
+}
+
+fn iv_array(ivs: &IVs) -> [IV; 6] {
+    return [
+        ivs.hp,
+        ivs.attack,
+        ivs.defense,
+        ivs.speed,
+        ivs.sp_attack,
+        ivs.sp_defense,
+    ];
+}
+
+fn range_array(iv_ranges: &IVRanges) -> [IVRange; 6] {
+    return [
+        iv_ranges.hp.clone(),
+        iv_ranges.attack.clone(),
+        iv_ranges.defense.clone(),
+        iv_ranges.speed.clone(),
+        iv_ranges.sp_attack.clone(),
+        iv_ranges.sp_defense.clone(),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn characteristic_test() {
+        let ivs = IVs::from_groups([31, 20, 31], [5, 31, 0]);
+
+        // hp, defense, sp_attack が 31 で並ぶので PID % 6 から巡る
+        assert_eq!(Characteristic::from_ivs(&ivs, 0).stat, 0);
+        assert_eq!(Characteristic::from_ivs(&ivs, 1).stat, 2);
+        assert_eq!(Characteristic::from_ivs(&ivs, 3).stat, 4);
+        assert_eq!(Characteristic::from_ivs(&ivs, 5).stat, 0);
+        assert_eq!(Characteristic::from_ivs(&ivs, 5).value, 1);
+
+        let full = 0..=31;
+        let iv_ranges = IVRanges {
+            hp: full.clone(),
+            attack: 10..=31,
+            defense: full.clone(),
+            speed: full.clone(),
+            sp_attack: full.clone(),
+            sp_defense: full.clone(),
+        };
+        let narrowed = Characteristic { stat: 3, value: 2 }
+            .narrow_iv_ranges(&iv_ranges)
+            .unwrap();
+
+        assert_eq!(narrowed.speed, 12..=27);
+        assert_eq!(narrowed.attack, 10..=27);
+        assert_eq!(narrowed.hp, 0..=27);
+
+        let impossible = IVRanges {
+            speed: 0..=6,
+            ..iv_ranges
+        };
+        assert!(
+            Characteristic { stat: 3, value: 2 }
+                .narrow_iv_ranges(&impossible)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/*
+    タマゴの個体値がどこから来たか。
+    Parent0: 先親 | Parent1: 後親 | Random: 遺伝せず乱数で決まった
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IVSource {
+    Random,
+    Parent0,
+    Parent1,
+}
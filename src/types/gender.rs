@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use super::seed::*;
+
+// 性別の閾値 (gender_ratio) の特殊な値
+pub const MALE_ONLY: u8 = 0x00;
+pub const FEMALE_ONLY: u8 = 0xfe;
+pub const GENDERLESS: u8 = 0xff;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+impl Gender {
+    /*
+        PID の下位 8bit (性別値) が gender_ratio 未満ならメス。
+        gender_ratio: 0x1f: ♂7/8, 0x3f: ♂3/4, 0x7f: ♂1/2, 0xbf: ♂1/4 (MALE_ONLY / FEMALE_ONLY / GENDERLESS は固定)
+    */
+    pub fn from_pid(pid: PID, gender_ratio: u8) -> Self {
+        return match gender_ratio {
+            MALE_ONLY => Gender::Male,
+            FEMALE_ONLY => Gender::Female,
+            GENDERLESS => Gender::Genderless,
+            _ if ((pid & 0xff) as u8) < gender_ratio => Gender::Female,
+            _ => Gender::Male,
+        };
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+// 個体値の最下位 bit から決まるタイプの並び
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HiddenPowerType {
+    Fighting,
+    Flying,
+    Poison,
+    Ground,
+    Rock,
+    Bug,
+    Ghost,
+    Steel,
+    Fire,
+    Water,
+    Grass,
+    Electric,
+    Psychic,
+    Ice,
+    Dragon,
+    Dark,
+}
+
+impl HiddenPowerType {
+    pub const ALL: [HiddenPowerType; 16] = [
+        HiddenPowerType::Fighting,
+        HiddenPowerType::Flying,
+        HiddenPowerType::Poison,
+        HiddenPowerType::Ground,
+        HiddenPowerType::Rock,
+        HiddenPowerType::Bug,
+        HiddenPowerType::Ghost,
+        HiddenPowerType::Steel,
+        HiddenPowerType::Fire,
+        HiddenPowerType::Water,
+        HiddenPowerType::Grass,
+        HiddenPowerType::Electric,
+        HiddenPowerType::Psychic,
+        HiddenPowerType::Ice,
+        HiddenPowerType::Dragon,
+        HiddenPowerType::Dark,
+    ];
+}
+
+/*
+    めざめるパワーのタイプと威力 (30 ~ 70)
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HiddenPower {
+    pub hp_type: HiddenPowerType,
+    pub power: u8,
+}
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
 use std::ops::RangeInclusive;
 
+use super::hidden_power::*;
+
 pub type IV = u8; // 0 ~ 31
 pub type IVRange = RangeInclusive<IV>;
 pub type IVGroup = [IV; 3];
 pub type IVRangeGroup = [IVRange; 3];
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IVs {
     pub hp: IV,
     pub attack: IV,
@@ -16,7 +18,50 @@ pub struct IVs {
     pub sp_defense: IV,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl IVs {
+    // 個体値1 (hp, attack, defense) と 個体値2 (speed, sp_attack, sp_defense) のグループから組み立てる
+    pub fn from_groups(iv_group_1st: IVGroup, iv_group_2nd: IVGroup) -> Self {
+        return Self {
+            hp: iv_group_1st[0],
+            attack: iv_group_1st[1],
+            defense: iv_group_1st[2],
+            speed: iv_group_2nd[0],
+            sp_attack: iv_group_2nd[1],
+            sp_defense: iv_group_2nd[2],
+        };
+    }
+
+    /*
+        めざめるパワー。hp, attack, defense, speed, sp_attack, sp_defense の順に 1, 2, 4, ... の重みで
+        タイプは各個体値の最下位 bit、威力は下から 2 bit 目を足し合わせて決まる。
+    */
+    pub fn hidden_power(&self) -> HiddenPower {
+        let ivs = [
+            self.hp,
+            self.attack,
+            self.defense,
+            self.speed,
+            self.sp_attack,
+            self.sp_defense,
+        ];
+        let (type_sum, power_sum) =
+            ivs.iter()
+                .enumerate()
+                .fold((0u16, 0u16), |(type_sum, power_sum), (i, iv)| {
+                    (
+                        type_sum | ((iv & 1) as u16) << i,
+                        power_sum | (((iv >> 1) & 1) as u16) << i,
+                    )
+                });
+
+        return HiddenPower {
+            hp_type: HiddenPowerType::ALL[(type_sum * 15 / 63) as usize],
+            power: (power_sum * 40 / 63) as u8 + 30,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IVRanges {
     pub hp: IVRange,
     pub attack: IVRange,
@@ -25,3 +70,34 @@ pub struct IVRanges {
     pub sp_attack: IVRange,
     pub sp_defense: IVRange,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_power_test() {
+        let hidden_power = |ivs: [IV; 6]| {
+            IVs::from_groups([ivs[0], ivs[1], ivs[2]], [ivs[3], ivs[4], ivs[5]]).hidden_power()
+        };
+        let expected = |hp_type, power| HiddenPower { hp_type, power };
+
+        assert_eq!(hidden_power([31; 6]), expected(HiddenPowerType::Dark, 70));
+        assert_eq!(
+            hidden_power([30; 6]),
+            expected(HiddenPowerType::Fighting, 70)
+        );
+        assert_eq!(
+            hidden_power([0; 6]),
+            expected(HiddenPowerType::Fighting, 30)
+        );
+        assert_eq!(
+            hidden_power([31, 30, 31, 30, 30, 31]),
+            expected(HiddenPowerType::Fire, 70)
+        );
+        assert_eq!(
+            hidden_power([31, 30, 30, 31, 31, 31]),
+            expected(HiddenPowerType::Ice, 70)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/*
+    先頭のポケモンの特性。
+    Synchronize: シンクロ (中身は押し付ける性格)
+    CuteCharm: メロメロボディ (先頭が♀か、出現するポケモンの性別の閾値)
+    CompoundEyes: ふくがん (持ち物の確率が上がる。性格の決め方は None と同じ)
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Lead {
+    #[default]
+    None,
+    Synchronize(u8),
+    CuteCharm {
+        female_lead: bool,
+        gender_ratio: u8,
+    },
+    CompoundEyes,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/*
+    PID と個体値の乱数の並び方。
+    Method1: PID下位 -> PID上位 -> 個体値1 -> 個体値2
+    Method2: PID下位 -> PID上位 -> (空き) -> 個体値1 -> 個体値2
+    Method4: PID下位 -> PID上位 -> 個体値1 -> (空き) -> 個体値2
+    空きは VBlank の割り込みで乱数が 1 回余分に消費されたもの。
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Method {
+    Method1,
+    Method2,
+    Method4,
+}
+
+impl Method {
+    // PID上位のシードから個体値1のシードまでの消費数
+    pub fn pid_to_iv_advances(&self) -> u32 {
+        return match self {
+            Method::Method1 | Method::Method4 => 1,
+            Method::Method2 => 2,
+        };
+    }
+
+    // 個体値1のシードから個体値2のシードまでの消費数
+    pub fn iv_to_iv_advances(&self) -> u32 {
+        return match self {
+            Method::Method1 | Method::Method2 => 1,
+            Method::Method4 => 2,
+        };
+    }
+}
@@ -1,3 +1,16 @@
+pub mod characteristic;
+pub mod egg;
+pub mod gender;
+pub mod hidden_power;
 pub mod iv;
+pub mod lead;
+pub mod method;
+pub mod pid_traits;
+pub mod radar;
+pub mod roamer;
 pub mod seed;
+pub mod species;
+pub mod stats;
 pub mod status;
+pub mod version;
+pub mod wild;
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use super::seed::*;
+
+const WURMPLE: u16 = 265;
+const SPINDA: u16 = 327;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WurmpleEvolution {
+    Silcoon,
+    Cascoon,
+}
+
+// パッチールのぶちの位置 (0 ~ 15)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpindaSpot {
+    pub x: u8,
+    pub y: u8,
+}
+
+/*
+    PID だけで決まる特徴。
+    species を指定した場合は、その種族に関係するものだけを返す (None なら全て)。
+    ability_slot: 特性の番号 (PID & 1)。特性が 1 つしかない種族ではどちらも同じ特性になる。
+    アンノーンの形は第4世代では PID と別に保存されるため含まない (PID から決まるのは第3世代)。
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PidTraits {
+    pub wurmple_evolution: Option<WurmpleEvolution>,
+    pub spinda_spots: Option<[SpindaSpot; 4]>,
+    pub ability_slot: u8,
+}
+
+impl PidTraits {
+    pub fn decode(pid: PID, species: Option<u16>) -> Self {
+        let relevant = |target: u16| species.is_none_or(|species| species == target);
+
+        return Self {
+            wurmple_evolution: relevant(WURMPLE).then(|| wurmple_evolution(pid)),
+            spinda_spots: relevant(SPINDA).then(|| spinda_spots(pid)),
+            ability_slot: (pid & 1) as u8,
+        };
+    }
+}
+
+/*
+    PID上位 % 10 が 5 未満ならカラサリス、それ以外はマユルド
+*/
+fn wurmple_evolution(pid: PID) -> WurmpleEvolution {
+    return if (pid >> 16) % 10 < 5 {
+        WurmpleEvolution::Silcoon
+    } else {
+        WurmpleEvolution::Cascoon
+    };
+}
+
+/*
+    下位バイトから順に 1 つ目 ~ 4 つ目のぶち。各バイトの下位 4bit が x、上位 4bit が y。
+*/
+fn spinda_spots(pid: PID) -> [SpindaSpot; 4] {
+    return std::array::from_fn(|i| {
+        let byte = (pid >> (8 * i)) as u8;
+        SpindaSpot {
+            x: byte & 0x0f,
+            y: byte >> 4,
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pid_traits_test() {
+        assert_eq!(wurmple_evolution(0x0004ffff), WurmpleEvolution::Silcoon);
+        assert_eq!(wurmple_evolution(0x0005ffff), WurmpleEvolution::Cascoon);
+        assert_eq!(wurmple_evolution(0x000a0000), WurmpleEvolution::Silcoon);
+
+        assert_eq!(
+            spinda_spots(0x12345678),
+            [
+                SpindaSpot { x: 8, y: 7 },
+                SpindaSpot { x: 6, y: 5 },
+                SpindaSpot { x: 4, y: 3 },
+                SpindaSpot { x: 2, y: 1 },
+            ]
+        );
+
+        let traits = PidTraits::decode(0x12345679, Some(WURMPLE));
+        assert_eq!(traits.wurmple_evolution, Some(WurmpleEvolution::Silcoon));
+        assert!(traits.spinda_spots.is_none());
+        assert_eq!(traits.ability_slot, 1);
+    }
+
+    #[test]
+    fn unown_pid_traits_test() {
+        // 第4世代のアンノーンの形は PID と別に保存されるので、特性の番号以外は分からない
+        let traits = PidTraits::decode(0x12345679, Some(201));
+        assert_eq!(
+            traits,
+            PidTraits {
+                wurmple_evolution: None,
+                spinda_spots: None,
+                ability_slot: 1,
+            }
+        );
+    }
+}
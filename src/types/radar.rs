@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use super::status::*;
+
+/*
+    ポケトレで揺れるくさむら 1 つ分。
+    ring: プレイヤーからの距離 (1 ~ 4)
+    position: その距離の外周 (8 * ring マス) のうち、何番目のマスか
+    continues: 連鎖が続くくさむらか
+    shiny: 光るくさむら (色違い) か
+*/
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RadarPatch {
+    pub ring: u8,
+    pub position: u8,
+    pub continues: bool,
+    pub shiny: bool,
+}
+
+/*
+    あるフレームでポケトレを使ったときの結果。
+    encounter は揺れたくさむらに入ったときに出現する個体 (光るくさむらがあればその個体)。
+*/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarFrame {
+    pub advances: u32,
+    pub patches: Vec<RadarPatch>,
+    pub encounter: Status,
+}
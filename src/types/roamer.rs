@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use super::{seed::*, status::*};
+
+/*
+    徘徊ポケモン。
+    HGSS: ライコウ/エンテイ/ラティアス/ラティオス
+    Pt: エムリット/クレセリア/フリーザー/サンダー/ファイヤー
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Roamer {
+    Raikou,
+    Entei,
+    Latias,
+    Latios,
+    Mesprit,
+    Cresselia,
+    Articuno,
+    Zapdos,
+    Moltres,
+}
+
+impl Roamer {
+    // 全国図鑑番号
+    pub fn species(&self) -> u16 {
+        return match self {
+            Roamer::Raikou => 243,
+            Roamer::Entei => 244,
+            Roamer::Latias => 380,
+            Roamer::Latios => 381,
+            Roamer::Mesprit => 481,
+            Roamer::Cresselia => 488,
+            Roamer::Articuno => 144,
+            Roamer::Zapdos => 145,
+            Roamer::Moltres => 146,
+        };
+    }
+
+    pub fn level(&self) -> u8 {
+        return match self {
+            Roamer::Raikou | Roamer::Entei => 40,
+            Roamer::Latias | Roamer::Latios => 35,
+            Roamer::Mesprit | Roamer::Cresselia => 50,
+            Roamer::Articuno | Roamer::Zapdos | Roamer::Moltres => 60,
+        };
+    }
+
+    /*
+        Pt の徘徊ポケモンは個体値が 1byte に切り詰められて保存されるため、
+        HP の個体値と攻撃の個体値の下位 3bit 以外は 0 になる。
+    */
+    pub fn truncates_ivs(&self) -> bool {
+        return matches!(
+            self,
+            Roamer::Mesprit
+                | Roamer::Cresselia
+                | Roamer::Articuno
+                | Roamer::Zapdos
+                | Roamer::Moltres
+        );
+    }
+}
+
+/*
+    status: 実際に出会う個体 (個体値は切り詰め後)
+    origin_seed: 生成を始める直前のシード (次のシードの乱数が PID下位 に使われる)
+*/
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RoamerStatus {
+    pub roamer: Roamer,
+    pub status: Status,
+    pub origin_seed: Seed,
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use super::seed::*;
+
+// 種族値 (並びは IVs と同じ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaseStats {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub sp_attack: u8,
+    pub sp_defense: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EggGroup {
+    Monster,
+    Water1,
+    Bug,
+    Flying,
+    Field,
+    Fairy,
+    Grass,
+    HumanLike,
+    Water3,
+    Mineral,
+    Amorphous,
+    Water2,
+    Ditto,
+    Dragon,
+    Undiscovered,
+}
+
+// 経験値タイプ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GrowthRate {
+    MediumFast,
+    Erratic,
+    Fluctuating,
+    MediumSlow,
+    Fast,
+    Slow,
+}
+
+/*
+    第4世代の種族データ。
+    abilities / egg_groups は 1 つしかない場合は同じ値が 2 つ並ぶ (ゲーム内のデータと同じ)。
+    gender_ratio: 性別の閾値 (Gender::from_pid を参照)
+*/
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SpeciesInfo {
+    pub dex: u16,
+    pub name: &'static str,
+    pub base_stats: BaseStats,
+    pub gender_ratio: u8,
+    pub abilities: [&'static str; 2],
+    pub egg_groups: [EggGroup; 2],
+    pub growth_rate: GrowthRate,
+}
+
+impl SpeciesInfo {
+    // PID & 1 で選ばれる特性
+    pub fn ability(&self, pid: PID) -> &'static str {
+        return self.abilities[(pid & 1) as usize];
+    }
+}
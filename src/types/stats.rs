@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+// 能力値や努力値 (並びは IVs と同じ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub sp_attack: u16,
+    pub sp_defense: u16,
+}
+
+impl Stats {
+    pub fn to_array(self) -> [u16; 6] {
+        return [
+            self.hp,
+            self.attack,
+            self.defense,
+            self.speed,
+            self.sp_attack,
+            self.sp_defense,
+        ];
+    }
+}
+
+/*
+    あるレベルで確認した能力値と、そのときの努力値
+*/
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct StatObservation {
+    pub level: u8,
+    pub stats: Stats,
+    pub evs: Stats,
+}
@@ -1,13 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use super::{iv::*, seed::*};
+use super::{characteristic::*, iv::*, seed::*};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Status {
     pub ivs: IVs,
-    pub gender: u8,
+    pub gender: u8, // 性別値 (PID の下位 8bit)。性別は種族の閾値と比べて決まる
     pub nature: u8,
     pub ability: u8,
     pub shiny: bool,
     pub pid: PID,
+    pub characteristic: Characteristic,
 }
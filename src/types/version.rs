@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use super::wild::WildMethod;
+
+/*
+    ソフトのバージョン。仕様の違いがあるものだけ区別する。
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameVersion {
+    DiamondPearl,
+    Platinum,
+    HeartGoldSoulSilver,
+}
+
+impl GameVersion {
+    // DPt はタマゴの個体値の遺伝で、選ばれた能力の削除を誤る
+    pub fn has_inheritance_bug(&self) -> bool {
+        return matches!(self, GameVersion::DiamondPearl | GameVersion::Platinum);
+    }
+
+    // 野生 (と先頭の特性が関係する固定シンボル) の生成方式
+    pub fn wild_method(&self) -> WildMethod {
+        return match self {
+            GameVersion::DiamondPearl | GameVersion::Platinum => WildMethod::MethodJ,
+            GameVersion::HeartGoldSoulSilver => WildMethod::MethodK,
+        };
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use super::{seed::*, status::*};
+
+/*
+    野生の生成方式。
+    MethodJ: ダイヤモンド/パール/プラチナ
+    MethodK: ハートゴールド/ソウルシルバー
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WildMethod {
+    MethodJ,
+    MethodK,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EncounterType {
+    Grass,
+    Surfing,
+    OldRod,
+    GoodRod,
+    SuperRod,
+    RockSmash,
+    Headbutt,
+    Static, // 固定シンボル (出現スロットとレベルの乱数を使わない)
+}
+
+impl EncounterType {
+    // 固定シンボル以外は最初に出現スロットの乱数を消費する
+    pub fn has_slot_rand(&self) -> bool {
+        return !matches!(self, EncounterType::Static);
+    }
+
+    // くさむらと固定シンボル以外はスロットごとにレベルの幅があり、スロットの次にレベルの乱数を消費する
+    pub fn has_level_rand(&self) -> bool {
+        return !matches!(self, EncounterType::Grass | EncounterType::Static);
+    }
+}
+
+/*
+    持ち物の判定結果。
+    Common: 50% の持ち物 (ふくがんなら 60%)
+    Rare: 5% の持ち物 (ふくがんなら 20%)
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeldItem {
+    None,
+    Common,
+    Rare,
+}
+
+/*
+    出現スロット 1 つ分の出現データ。くさむらは min_level == max_level。
+*/
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EncounterSlot {
+    pub species: u16, // 全国図鑑番号
+    pub min_level: u8,
+    pub max_level: u8,
+}
+
+/*
+    場所ごとの出現データ。slots はスロット番号順に並べる。
+*/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterArea {
+    pub slots: Vec<EncounterSlot>,
+}
+
+/*
+    area: 出現データ (指定した場合はスロットから種族とレベルまで求める)
+*/
+#[derive(Debug, Clone, Deserialize)]
+pub struct WildParams {
+    pub method: WildMethod,
+    pub encounter_type: EncounterType,
+    pub area: Option<EncounterArea>,
+}
+
+/*
+    origin_seed: 生成を始める直前のシード (次のシードの乱数が出現スロットに使われる)
+    slot_value: 出現スロットを決める値 (0 ~ 99, 固定シンボルでは消費しない)
+    encounter_slot: 出現スロットの番号
+    species, level: 出現データがある場合の種族 (全国図鑑番号) とレベル
+    level_rand: レベルを決める乱数 (くさむらでは消費しない)
+    synchronized: シンクロ判定に成功したか
+    cute_charmed: メロメロボディ判定に成功したか
+    pid_rerolls: 性格が一致するまでに捨てた PID の数
+    held_item: 持ち物の判定結果
+*/
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WildStatus {
+    pub status: Status,
+    pub origin_seed: Seed,
+    pub slot_value: Option<u8>,
+    pub encounter_slot: Option<u8>,
+    pub species: Option<u16>,
+    pub level: Option<u8>,
+    pub level_rand: Option<Rand>,
+    pub synchronized: bool,
+    pub cute_charmed: bool,
+    pub pid_rerolls: u32,
+    pub held_item: HeldItem,
+}